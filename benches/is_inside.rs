@@ -0,0 +1,39 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use rust_jp_mesh::{Coordinates, JPMesh, JPMeshType};
+
+/// 東京駅周辺の 1km メッシュの内外にまたがる座標列
+fn points() -> Vec<Coordinates> {
+    (0..1024)
+        .map(|i| {
+            let t = i as f64 / 1024.0;
+            Coordinates::new(139.75 + t * 0.03, 35.67 + t * 0.02)
+        })
+        .collect()
+}
+
+fn bench_is_inside(c: &mut Criterion) {
+    let mesh = JPMesh::new(Coordinates::new(139.767125, 35.681236), JPMeshType::Mesh1km);
+    let points = points();
+
+    let mut group = c.benchmark_group("is_inside");
+    group.bench_function("grid_index", |b| {
+        b.iter(|| {
+            points
+                .iter()
+                .filter(|&&p| black_box(mesh).is_inside(p))
+                .count()
+        })
+    });
+    group.bench_function("to_bounds", |b| {
+        b.iter(|| {
+            points
+                .iter()
+                .filter(|&&p| black_box(mesh).to_bounds().includes(p))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_is_inside);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use rust_jp_mesh::{Coordinates, JPMesh, JPMeshType};
+
+/// 東京駅周辺の座標列
+fn points() -> Vec<Coordinates> {
+    (0..1024)
+        .map(|i| {
+            let t = i as f64 / 1024.0;
+            Coordinates::new(139.75 + t * 0.3, 35.67 + t * 0.2)
+        })
+        .collect()
+}
+
+fn bench_to_bounds(c: &mut Criterion) {
+    let points = points();
+    let meshes = JPMeshType::all()
+        .into_iter()
+        .flat_map(|mesh_type| points.iter().map(move |&p| JPMesh::new(p, mesh_type)))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("to_bounds");
+    group.bench_function("to_bounds", |b| {
+        b.iter(|| {
+            meshes
+                .iter()
+                .map(|mesh| black_box(mesh).to_bounds().min().lat)
+                .sum::<f64>()
+        })
+    });
+    group.bench_function("new", |b| {
+        b.iter(|| {
+            JPMeshType::all()
+                .into_iter()
+                .flat_map(|mesh_type| points.iter().map(move |&p| JPMesh::new(p, mesh_type)))
+                .map(black_box)
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_to_bounds);
+criterion_main!(benches);
@@ -31,16 +31,12 @@ struct GeoJsonGeometry {
 }
 
 fn rect_to_polygon(rect: Rect) -> Vec<Vec<Vec<f64>>> {
-    let min = rect.min();
-    let max = rect.max();
-
-    vec![vec![
-        vec![min.lng, min.lat],
-        vec![max.lng, min.lat],
-        vec![max.lng, max.lat],
-        vec![min.lng, max.lat],
-        vec![min.lng, min.lat],
-    ]]
+    vec![
+        rect.as_polygon(false)
+            .iter()
+            .map(|coords| coords.as_array().to_vec())
+            .collect(),
+    ]
 }
 
 fn create_geojson_for_mesh_type(bounds: Rect, mesh_type: JPMeshType) -> GeoJsonFeatureCollection {
@@ -0,0 +1,76 @@
+use rust_jp_mesh::{Coordinates, JPMesh, JPMeshType};
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "使い方: point_to_mesh_cli [--level <80km|10km|5km|2km|1km|500m|250m|125m>] [--hyphenated] < points.csv";
+
+/// `経度,緯度` の形式の行を座標に変換します。
+fn parse_coordinates(line: &str) -> Option<Coordinates> {
+    let (lng, lat) = line.split_once(',')?;
+    let lng = lng.trim().parse::<f64>().ok()?;
+    let lat = lat.trim().parse::<f64>().ok()?;
+    Some(Coordinates::new(lng, lat))
+}
+
+fn main() -> ExitCode {
+    let mut mesh_type = JPMeshType::Mesh1km;
+    let mut hyphenated = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--level" => match args.next().unwrap_or_default().parse::<JPMeshType>() {
+                Ok(level) => mesh_type = level,
+                Err(err) => {
+                    eprintln!("{}\n{}", err, USAGE);
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--hyphenated" => hyphenated = true,
+            _ => {
+                eprintln!("不明な引数です: {}\n{}", arg, USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let stdin = io::stdin();
+    let mut stdout = io::stdout().lock();
+
+    for (i, line) in stdin.lock().lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                eprintln!("標準入力の読み込みに失敗しました: {}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some(coords) = parse_coordinates(&line) else {
+            eprintln!("{}行目: 座標として解釈できません: {}", i + 1, line);
+            continue;
+        };
+
+        let mesh = match JPMesh::try_new(coords, mesh_type) {
+            Ok(mesh) => mesh,
+            Err(err) => {
+                eprintln!("{}行目: {}: {}", i + 1, err, line);
+                continue;
+            }
+        };
+        let written = if hyphenated {
+            writeln!(stdout, "{:#}", mesh)
+        } else {
+            writeln!(stdout, "{}", mesh)
+        };
+        if written.is_err() {
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,30 @@
+//! 任意の経度・緯度から地域メッシュを生成し、panic しないことと、生成した地域メッシュが座標を含むことを確認します。
+//!
+//! `cargo fuzz run from_coordinates`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_jp_mesh::{Coordinates, JPMesh, JPMeshType};
+
+fuzz_target!(|input: (f64, f64)| {
+    let coords = Coordinates::new(input.0, input.1);
+
+    for mesh_type in JPMeshType::all() {
+        // 範囲外の座標でも panic しない
+        let _ = JPMesh::new(coords, mesh_type).to_bounds();
+
+        if let Ok(mesh) = JPMesh::try_new(coords, mesh_type) {
+            assert!(mesh.is_inside(coords));
+            assert!(mesh.to_bounds().includes(coords));
+            assert_eq!(JPMesh::new(coords, mesh_type), mesh);
+            // 先頭の桁が0のメッシュコードは数値から生成できない
+            if mesh.to_string() == mesh.to_number().to_string() {
+                assert_eq!(
+                    JPMesh::try_from_number(mesh.to_number(), mesh_type),
+                    Ok(mesh)
+                );
+            }
+        }
+    }
+});
@@ -0,0 +1,37 @@
+//! 任意の数値をメッシュコードとして解釈し、panic しないことと、受け付けたメッシュコードが往復することを確認します。
+//!
+//! `cargo fuzz run from_number`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_jp_mesh::{JPMesh, JPMeshType};
+
+fuzz_target!(|n: u64| {
+    let _ = JPMesh::diagnose_number(n);
+
+    // 桁数から種類を推定する
+    if let Ok(mesh) = n.to_string().parse::<JPMesh>() {
+        assert_eq!(mesh.to_string(), n.to_string());
+        check_round_trip(mesh);
+    }
+
+    for mesh_type in JPMeshType::all() {
+        if let Ok(mesh) = JPMesh::try_from_number(n, mesh_type) {
+            assert_eq!(mesh.to_number(), n);
+            check_round_trip(mesh);
+        }
+    }
+});
+
+fn check_round_trip(mesh: JPMesh) {
+    let (col, row) = mesh.grid_index();
+    assert_eq!(
+        JPMesh::from_grid_index(col, row, mesh.mesh_type()),
+        Some(mesh)
+    );
+    assert_eq!(JPMesh::from_id(mesh.to_id()), Some(mesh));
+
+    let center = mesh.to_bounds().center();
+    assert_eq!(JPMesh::new(center, mesh.mesh_type()), mesh);
+}
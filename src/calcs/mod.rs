@@ -1,3 +1,82 @@
+use crate::{Coordinates, JPMeshError, JPMeshType, Rect};
+
 pub mod to_125m;
 pub mod to_2km;
 pub mod to_5km;
+
+/// 座標を、第1次地域区画の位置と第1次地域区画内の位置に分解します。
+///
+/// 戻り値は `(p, a, u, f)` で、それぞれ次の値を表します。
+/// - `p`: 緯度を第1次地域区画の緯度間隔で割った商
+/// - `a`: その余り (度)
+/// - `u`: 経度から `origin_lng` を引いた値の整数部
+/// - `f`: その小数部 (度)
+///
+/// 標準地域メッシュでは `origin_lng` に常に [`crate::mesh_type::ORIGIN_LNG`] (経度100度) を渡します。
+/// 原点をずらした格子を試すための内部向けの関数であり、公開している処理はすべて標準の原点を使います。
+pub(crate) fn decompose(coords: Coordinates, origin_lng: f64) -> (u8, f64, u8, f64) {
+    // latitude / interval (Mesh80km) = p % a
+    let p = (coords.lat / JPMeshType::Mesh80km.lat_interval()).floor() as u8;
+    let a = coords.lat % JPMeshType::Mesh80km.lat_interval();
+
+    // longitude - origin_lng = u % f
+    let u = (coords.lng - origin_lng).floor() as u8;
+    let f = coords.lng - origin_lng - u as f64;
+
+    (p, a, u, f)
+}
+
+/// メッシュコードの内部表現が実装するべき処理
+///
+/// 新しい分割方式を追加する場合は、この trait を実装した型を [`crate::JPMesh`] のバリアントとして追加します。
+pub trait MeshCodeRepr: Sized + Copy {
+    /// 指定された座標を含むメッシュのコードを生成します。
+    fn from_coordinates(coords: Coordinates, mesh_type: JPMeshType) -> Self;
+
+    /// メッシュコードの数値から生成します。
+    ///
+    /// 数値の桁数が内部表現の桁数を超える場合は [`JPMeshError::InvalidCodeLength`] を返します。
+    fn from_number(number: u64) -> Result<Self, JPMeshError>;
+
+    /// メッシュコードの各桁が、この分割方式で取りうる値であるかを検証します。
+    ///
+    /// 取りえない値を含む場合は [`JPMeshError::InvalidDigit`] を返します。
+    fn validate(self) -> Result<Self, JPMeshError> {
+        Ok(self)
+    }
+
+    /// 格子上の位置 (経度方向の列番号, 緯度方向の行番号) から生成します。
+    fn from_grid_index(col: u64, row: u64, mesh_type: JPMeshType) -> Self;
+
+    /// メッシュの範囲を表す矩形を取得します。
+    fn to_bounds(self, mesh_type: JPMeshType) -> Rect {
+        let (col, row) = self.to_grid_index(mesh_type);
+        mesh_type.grid_cell_bounds(col, row)
+    }
+
+    /// メッシュコードの数値を取得します。
+    fn to_number(self, mesh_type: JPMeshType) -> u64;
+
+    /// 格子上の位置 (経度方向の列番号, 緯度方向の行番号) を取得します。
+    fn to_grid_index(self, mesh_type: JPMeshType) -> (u64, u64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_type::ORIGIN_LNG;
+
+    #[test]
+    fn test_decompose() {
+        let coords = Coordinates::new(139.5, 35.5);
+
+        let (p, a, u, f) = decompose(coords, ORIGIN_LNG);
+        assert_eq!((p, u, f), (53, 39, 0.5));
+        assert!((a - (35.5 - 53.0 * 2.0 / 3.0)).abs() < 1e-12);
+
+        // 原点をずらすと経度方向の分解だけが変わる
+        let (shifted_p, shifted_a, shifted_u, shifted_f) = decompose(coords, 139.0);
+        assert_eq!((shifted_p, shifted_a), (p, a));
+        assert_eq!((shifted_u, shifted_f), (0, 0.5));
+    }
+}
@@ -1,18 +1,17 @@
-use crate::{Coordinates, JPMeshType, Rect, code_num::CodeNum};
+use crate::{
+    Coordinates, JPMeshError, JPMeshType,
+    calcs::{MeshCodeRepr, decompose},
+    code_num::CodeNum,
+    mesh_type::ORIGIN_LNG,
+};
 
 // D=11 は最大桁数
 // E=7 (2進数: 00000000111) となっているのは、下三桁は空間を2x2分割し1-4の値をとる桁であり、使わない場合は0でなく1としなければ座標がずれるため
 pub type CodeTo125m = CodeNum<11, 7>;
 
-impl CodeTo125m {
-    pub fn from_coordinates(coords: Coordinates, mesh_type: JPMeshType) -> Self {
-        // latitude / interval (Mesh80km) = p % a
-        let p = (coords.lat / JPMeshType::Mesh80km.lat_interval()).floor() as u8;
-        let a = coords.lat % JPMeshType::Mesh80km.lat_interval();
-
-        // longitude - 100 degrees = u % f
-        let u = (coords.lng - 100.0).floor() as u8;
-        let f = coords.lng - 100.0 - u as f64;
+impl MeshCodeRepr for CodeTo125m {
+    fn from_coordinates(coords: Coordinates, mesh_type: JPMeshType) -> Self {
+        let (p, a, u, f) = decompose(coords, ORIGIN_LNG);
 
         let p1 = (p / 10) % 10;
         let p2 = p % 10;
@@ -81,46 +80,65 @@ impl CodeTo125m {
         CodeNum::new(&[p1, p2, u1, u2, q, v, r, w, m, n, nn])
     }
 
-    pub fn to_bounds(self, mesh_type: JPMeshType) -> Rect {
+    fn from_number(number: u64) -> Result<Self, JPMeshError> {
+        CodeNum::from_number(number)
+    }
+
+    fn to_number(self, mesh_type: JPMeshType) -> u64 {
+        CodeNum::to_number(self, mesh_type.code_length())
+            .expect("code length of the mesh type exceeds its code representation")
+    }
+
+    fn to_grid_index(self, mesh_type: JPMeshType) -> (u64, u64) {
         let code_array = self.to_array();
 
-        let p = (code_array[0] * 10 + code_array[1]) as f64;
-        let u = (code_array[2] * 10 + code_array[3]) as f64;
-        let q = code_array[4] as f64;
-        let v = code_array[5] as f64;
-        let r = code_array[6] as f64;
-        let w = code_array[7] as f64;
-        let m = code_array[8] as f64;
-        let n = code_array[9] as f64;
-        let nn = code_array[10] as f64;
-
-        // Calculate latitude (southwest corner)
-        let lat_base = p * JPMeshType::Mesh80km.lat_interval();
-        let lat_q = q * JPMeshType::Mesh10km.lat_interval();
-        let lat_r = r * JPMeshType::Mesh1km.lat_interval();
-        let lat_s = ((m - 1.0) / 2.0).floor() * JPMeshType::Mesh500m.lat_interval();
-        let lat_t = ((n - 1.0) / 2.0).floor() * JPMeshType::Mesh250m.lat_interval();
-        let lat_tt = ((nn - 1.0) / 2.0).floor() * JPMeshType::Mesh125m.lat_interval();
-
-        // Calculate longitude (southwest corner)
-        let lng_base = 100.0 + u;
-        let lng_v = v * JPMeshType::Mesh10km.lng_interval();
-        let lng_w = w * JPMeshType::Mesh1km.lng_interval();
-        let lng_x = ((m - 1.0) % 2.0) * JPMeshType::Mesh500m.lng_interval();
-        let lng_y = ((n - 1.0) % 2.0) * JPMeshType::Mesh250m.lng_interval();
-        let lng_yy = ((nn - 1.0) % 2.0) * JPMeshType::Mesh125m.lng_interval();
-
-        // Coordinates of southwest corner
-        let min_lat = lat_base + lat_q + lat_r + lat_s + lat_t + lat_tt;
-        let min_lng = lng_base + lng_v + lng_w + lng_x + lng_y + lng_yy;
-
-        // Coordinates of northeast corner
-        let max_lat = min_lat + mesh_type.lat_interval();
-        let max_lng = min_lng + mesh_type.lng_interval();
-
-        Rect::new(
-            Coordinates::new(min_lng, min_lat),
-            Coordinates::new(max_lng, max_lat),
-        )
+        let p = (code_array[0] * 10 + code_array[1]) as u64;
+        let u = (code_array[2] * 10 + code_array[3]) as u64;
+        let q = code_array[4] as u64;
+        let v = code_array[5] as u64;
+        let r = code_array[6] as u64;
+        let w = code_array[7] as u64;
+
+        let mut row = (p * 8 + q) * 10 + r;
+        let mut col = (u * 8 + v) * 10 + w;
+
+        // m, n, nn = (row * 2) + (col + 1)
+        for &digit in &code_array[8..mesh_type.code_length()] {
+            row = row * 2 + ((digit - 1) / 2) as u64;
+            col = col * 2 + ((digit - 1) % 2) as u64;
+        }
+
+        (col, row)
+    }
+
+    fn from_grid_index(col: u64, row: u64, mesh_type: JPMeshType) -> Self {
+        let mut row = row;
+        let mut col = col;
+
+        let mut code_array = [0u8; 11];
+        let code_length = mesh_type.code_length();
+
+        for i in (8..code_length).rev() {
+            code_array[i] = ((row % 2) * 2 + (col % 2) + 1) as u8;
+            row /= 2;
+            col /= 2;
+        }
+
+        code_array[6] = (row % 10) as u8;
+        code_array[7] = (col % 10) as u8;
+        row /= 10;
+        col /= 10;
+
+        code_array[4] = (row % 8) as u8;
+        code_array[5] = (col % 8) as u8;
+        row /= 8;
+        col /= 8;
+
+        code_array[0] = (row / 10) as u8;
+        code_array[1] = (row % 10) as u8;
+        code_array[2] = (col / 10) as u8;
+        code_array[3] = (col % 10) as u8;
+
+        CodeNum::new(&code_array[..code_length])
     }
 }
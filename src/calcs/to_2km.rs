@@ -1,16 +1,15 @@
-use crate::{Coordinates, JPMeshType, Rect, code_num::CodeNum};
+use crate::{
+    Coordinates, JPMeshError, JPMeshType,
+    calcs::{MeshCodeRepr, decompose},
+    code_num::CodeNum,
+    mesh_type::ORIGIN_LNG,
+};
 
 pub type CodeTo2km = CodeNum<9, 0>;
 
-impl CodeTo2km {
-    pub fn from_coordinates(coords: Coordinates, _mesh_type: JPMeshType) -> Self {
-        // latitude / interval (Mesh80km) = p % a
-        let p = (coords.lat / JPMeshType::Mesh80km.lat_interval()).floor() as u8;
-        let a = coords.lat % JPMeshType::Mesh80km.lat_interval();
-
-        // longitude - 100 degrees = u % f
-        let u = (coords.lng - 100.0).floor() as u8;
-        let f = coords.lng - 100.0 - u as f64;
+impl MeshCodeRepr for CodeTo2km {
+    fn from_coordinates(coords: Coordinates, _mesh_type: JPMeshType) -> Self {
+        let (p, a, u, f) = decompose(coords, ORIGIN_LNG);
 
         let p1 = (p / 10) % 10;
         let p2 = p % 10;
@@ -37,40 +36,92 @@ impl CodeTo2km {
         CodeNum::new(&[p1, p2, u1, u2, q, v, r_code, w_code, 5])
     }
 
-    pub fn to_bounds(self, mesh_type: JPMeshType) -> Rect {
+    fn from_number(number: u64) -> Result<Self, JPMeshError> {
+        CodeNum::from_number(number)?.validate()
+    }
+
+    fn validate(self) -> Result<Self, JPMeshError> {
+        let code_array = self.to_array();
+
+        // r_code, w_code は 0, 2, 4, 6, 8 のいずれか、末尾は 5
+        for position in [6, 7] {
+            let digit = code_array[position];
+            if !digit.is_multiple_of(2) {
+                return Err(JPMeshError::InvalidDigit { position, digit });
+            }
+        }
+        if code_array[8] != 5 {
+            return Err(JPMeshError::InvalidDigit {
+                position: 8,
+                digit: code_array[8],
+            });
+        }
+
+        Ok(self)
+    }
+
+    fn to_number(self, mesh_type: JPMeshType) -> u64 {
+        CodeNum::to_number(self, mesh_type.code_length())
+            .expect("code length of the mesh type exceeds its code representation")
+    }
+
+    fn to_grid_index(self, _mesh_type: JPMeshType) -> (u64, u64) {
         let code_array = self.to_array();
 
-        let p = (code_array[0] * 10 + code_array[1]) as f64;
-        let u = (code_array[2] * 10 + code_array[3]) as f64;
-        let q = code_array[4] as f64;
-        let v = code_array[5] as f64;
-        let r_code = code_array[6] as f64;
-        let w_code = code_array[7] as f64;
-
-        let r = r_code / 2.0;
-        let w = w_code / 2.0;
-
-        // Calculate latitude (southwest corner)
-        let lat_base = p * JPMeshType::Mesh80km.lat_interval();
-        let lat_q = q * JPMeshType::Mesh10km.lat_interval();
-        let lat_r = r * JPMeshType::Mesh2km.lat_interval();
-
-        // Calculate longitude (southwest corner)
-        let lng_base = 100.0 + u;
-        let lng_v = v * JPMeshType::Mesh10km.lng_interval();
-        let lng_w = w * JPMeshType::Mesh2km.lng_interval();
-
-        // Coordinates of southwest corner
-        let min_lat = lat_base + lat_q + lat_r;
-        let min_lng = lng_base + lng_v + lng_w;
-
-        // Coordinates of northeast corner
-        let max_lat = min_lat + mesh_type.lat_interval();
-        let max_lng = min_lng + mesh_type.lng_interval();
-
-        Rect::new(
-            Coordinates::new(min_lng, min_lat),
-            Coordinates::new(max_lng, max_lat),
-        )
+        let p = (code_array[0] * 10 + code_array[1]) as u64;
+        let u = (code_array[2] * 10 + code_array[3]) as u64;
+        let q = code_array[4] as u64;
+        let v = code_array[5] as u64;
+        let r = (code_array[6] / 2) as u64;
+        let w = (code_array[7] / 2) as u64;
+
+        let row = (p * 8 + q) * 5 + r;
+        let col = (u * 8 + v) * 5 + w;
+
+        (col, row)
+    }
+
+    fn from_grid_index(col: u64, row: u64, _mesh_type: JPMeshType) -> Self {
+        let r_code = ((row % 5) * 2) as u8;
+        let w_code = ((col % 5) * 2) as u8;
+        let row = row / 5;
+        let col = col / 5;
+
+        let q = (row % 8) as u8;
+        let v = (col % 8) as u8;
+        let p = (row / 8) as u8;
+        let u = (col / 8) as u8;
+
+        CodeNum::new(&[p / 10, p % 10, u / 10, u % 10, q, v, r_code, w_code, 5])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_number_validation() {
+        let code = <CodeTo2km as MeshCodeRepr>::from_number(533946405).unwrap();
+        assert_eq!(
+            MeshCodeRepr::to_number(code, JPMeshType::Mesh2km),
+            533946405
+        );
+        assert_eq!(code.to_grid_index(JPMeshType::Mesh2km), (1590, 2142));
+
+        assert_eq!(
+            <CodeTo2km as MeshCodeRepr>::from_number(533946305),
+            Err(JPMeshError::InvalidDigit {
+                position: 6,
+                digit: 3
+            })
+        );
+        assert_eq!(
+            <CodeTo2km as MeshCodeRepr>::from_number(533946401),
+            Err(JPMeshError::InvalidDigit {
+                position: 8,
+                digit: 1
+            })
+        );
     }
 }
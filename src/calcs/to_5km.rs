@@ -1,16 +1,15 @@
-use crate::{Coordinates, JPMeshType, Rect, code_num::CodeNum};
+use crate::{
+    Coordinates, JPMeshError, JPMeshType,
+    calcs::{MeshCodeRepr, decompose},
+    code_num::CodeNum,
+    mesh_type::ORIGIN_LNG,
+};
 
 pub type CodeTo5km = CodeNum<7, 1>;
 
-impl CodeTo5km {
-    pub fn from_coordinates(coords: Coordinates, mesh_type: JPMeshType) -> Self {
-        // latitude / interval (Mesh80km) = p % a
-        let p = (coords.lat / JPMeshType::Mesh80km.lat_interval()).floor() as u8;
-        let a = coords.lat % JPMeshType::Mesh80km.lat_interval();
-
-        // longitude - 100 degrees = u % f
-        let u = (coords.lng - 100.0).floor() as u8;
-        let f = coords.lng - 100.0 - u as f64;
+impl MeshCodeRepr for CodeTo5km {
+    fn from_coordinates(coords: Coordinates, mesh_type: JPMeshType) -> Self {
+        let (p, a, u, f) = decompose(coords, ORIGIN_LNG);
 
         let p1 = (p / 10) % 10;
         let p2 = p % 10;
@@ -44,36 +43,65 @@ impl CodeTo5km {
         CodeNum::new(&[p1, p2, u1, u2, q, v, m])
     }
 
-    pub fn to_bounds(self, mesh_type: JPMeshType) -> Rect {
+    fn from_number(number: u64) -> Result<Self, JPMeshError> {
+        CodeNum::from_number(number)
+    }
+
+    fn to_number(self, mesh_type: JPMeshType) -> u64 {
+        CodeNum::to_number(self, mesh_type.code_length())
+            .expect("code length of the mesh type exceeds its code representation")
+    }
+
+    fn to_grid_index(self, mesh_type: JPMeshType) -> (u64, u64) {
         let code_array = self.to_array();
 
-        let p = (code_array[0] * 10 + code_array[1]) as f64;
-        let u = (code_array[2] * 10 + code_array[3]) as f64;
-        let q = code_array[4] as f64;
-        let v = code_array[5] as f64;
-        let m = code_array[6] as f64;
-
-        // Calculate latitude (southwest corner)
-        let lat_base = p * JPMeshType::Mesh80km.lat_interval();
-        let lat_q = q * JPMeshType::Mesh10km.lat_interval();
-        let lat_r = ((m - 1.0) / 2.0).floor() * JPMeshType::Mesh5km.lat_interval();
-
-        // Calculate longitude (southwest corner)
-        let lng_base = 100.0 + u;
-        let lng_v = v * JPMeshType::Mesh10km.lng_interval();
-        let lng_w = ((m - 1.0) % 2.0) * JPMeshType::Mesh5km.lng_interval();
-
-        // Coordinates of southwest corner
-        let min_lat = lat_base + lat_q + lat_r;
-        let min_lng = lng_base + lng_v + lng_w;
-
-        // Coordinates of northeast corner
-        let max_lat = min_lat + mesh_type.lat_interval();
-        let max_lng = min_lng + mesh_type.lng_interval();
-
-        Rect::new(
-            Coordinates::new(min_lng, min_lat),
-            Coordinates::new(max_lng, max_lat),
-        )
+        let p = (code_array[0] * 10 + code_array[1]) as u64;
+        let u = (code_array[2] * 10 + code_array[3]) as u64;
+
+        if mesh_type == JPMeshType::Mesh80km {
+            return (u, p);
+        }
+
+        let q = code_array[4] as u64;
+        let v = code_array[5] as u64;
+
+        let row = p * 8 + q;
+        let col = u * 8 + v;
+
+        if mesh_type == JPMeshType::Mesh10km {
+            return (col, row);
+        }
+
+        // m = (r * 2) + (w + 1)
+        let m = code_array[6] as u64;
+
+        (col * 2 + (m - 1) % 2, row * 2 + (m - 1) / 2)
+    }
+
+    fn from_grid_index(col: u64, row: u64, mesh_type: JPMeshType) -> Self {
+        let mut row = row;
+        let mut col = col;
+
+        let mut m = 0;
+        if mesh_type == JPMeshType::Mesh5km {
+            m = ((row % 2) * 2 + (col % 2) + 1) as u8;
+            row /= 2;
+            col /= 2;
+        }
+
+        let mut q = 0;
+        let mut v = 0;
+        if mesh_type != JPMeshType::Mesh80km {
+            q = (row % 8) as u8;
+            v = (col % 8) as u8;
+            row /= 8;
+            col /= 8;
+        }
+
+        let p = row as u8;
+        let u = col as u8;
+
+        let code_array = [p / 10, p % 10, u / 10, u % 10, q, v, m];
+        CodeNum::new(&code_array[..mesh_type.code_length()])
     }
 }
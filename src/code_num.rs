@@ -1,3 +1,5 @@
+use crate::JPMeshError;
+
 // - short_number: A number with unadjusted digits (e.g., 678954)
 // - large_number: A number adjusted to D digits (e.g., 67895400000, 67895432121)
 // - code_number: A number adjusted to D digits with digits defaulting to the binary representation of E (e.g., 67895400000 -> 67895400111 (D=11, E=7), 67895432121 -> 67895432121)
@@ -15,7 +17,7 @@
 /// For example:
 /// - CodeNum<11, 0> : CodeNum::from_number(678954, 6) -> CodeNum(67895400000)
 /// - CodeNum<11, 5> : CodeNum::from_number(678954, 6) -> CodeNum(67895400101)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct CodeNum<const D: usize, const E: u8>(u64);
 
 impl<const D: usize, const E: u8> Default for CodeNum<D, E> {
@@ -32,9 +34,14 @@ impl<const D: usize, const E: u8> CodeNum<D, E> {
     }
 
     /// Creates a new CodeNum instance from a number.
-    pub fn from_number(short_number: u64) -> Self {
+    /// Returns `JPMeshError::InvalidCodeLength` if the number has more than D digits.
+    pub fn from_number(short_number: u64) -> Result<Self, JPMeshError> {
+        let length = decimal_length(short_number);
+        if length > D {
+            return Err(JPMeshError::InvalidCodeLength(length));
+        }
         let large_array = short_number_to_large_array::<D, E>(short_number);
-        CodeNum(encode::<D, E>(large_array))
+        Ok(CodeNum(encode::<D, E>(large_array)))
     }
 
     /// Converts a CodeNum instance to an D-digit array.
@@ -42,17 +49,23 @@ impl<const D: usize, const E: u8> CodeNum<D, E> {
         code_number_to_code_array::<D, E>(self.0)
     }
 
-    /// Converts a CodeNum instance to a number.
-    pub fn to_number(self, code_length: usize) -> u64 {
+    /// Converts a CodeNum instance to a number with `code_length` digits.
+    /// Returns `JPMeshError::InvalidCodeLength` if `code_length` is 0 or greater than D.
+    pub fn to_number(self, code_length: usize) -> Result<u64, JPMeshError> {
         truncate::<D>(self.0, code_length)
     }
 }
 
+/// 678954 -> 6, 0 -> 1
+pub(crate) fn decimal_length(number: u64) -> usize {
+    number.checked_ilog10().map_or(1, |log| log as usize + 1)
+}
+
 /// 678954 -> [6, 7, 8, 9, 5, 4, 0, 0, 0, 0, 0] (when D=11)
 fn short_number_to_large_array<const D: usize, const E: u8>(short_number: u64) -> [u8; D] {
     let mut large_array = [0u8; D];
     let mut number = short_number;
-    while number < 10u64.pow((D - 1) as u32) {
+    while number != 0 && number < 10u64.pow((D - 1) as u32) {
         number *= 10;
     }
 
@@ -118,8 +131,15 @@ fn encode<const D: usize, const E: u8>(large_array: [u8; D]) -> u64 {
 }
 
 /// 67895432124 -> 6789 (code_length = 4), 6789543212 (code_length = 10) (when D=11)
-fn truncate<const D: usize>(large_number: u64, code_length: usize) -> u64 {
-    large_number / 10u64.pow(D as u32 - code_length as u32)
+/// Returns `JPMeshError::InvalidCodeLength` if code_length is 0 or greater than D.
+fn truncate<const D: usize>(large_number: u64, code_length: usize) -> Result<u64, JPMeshError> {
+    // 10^D must fit in u64
+    const { assert!(D <= 19) };
+
+    if code_length == 0 || code_length > D {
+        return Err(JPMeshError::InvalidCodeLength(code_length));
+    }
+    Ok(large_number / 10u64.pow((D - code_length) as u32))
 }
 
 #[cfg(test)]
@@ -138,6 +158,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_number_length() {
+        assert_eq!(
+            CodeNum::<11, 0>::from_number(678954).map(|c| c.to_array()),
+            Ok([6, 7, 8, 9, 5, 4, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            CodeNum::<11, 0>::from_number(0).map(|c| c.to_array()),
+            Ok([0; 11])
+        );
+        assert_eq!(
+            CodeNum::<11, 0>::from_number(678954321245),
+            Err(JPMeshError::InvalidCodeLength(12))
+        );
+    }
+
     #[test]
     fn test_large_array_to_code_array() {
         // E=7 (binary: 111)
@@ -196,7 +232,32 @@ mod tests {
 
     #[test]
     fn test_truncate() {
-        assert_eq!(truncate::<11>(67895432124, 4), 6789);
-        assert_eq!(truncate::<11>(67895432124, 10), 6789543212);
+        assert_eq!(truncate::<11>(67895432124, 4), Ok(6789));
+        assert_eq!(truncate::<11>(67895432124, 10), Ok(6789543212));
+        assert_eq!(truncate::<11>(67895432121, 11), Ok(67895432121));
+        assert_eq!(
+            truncate::<11>(67895432121, 12),
+            Err(JPMeshError::InvalidCodeLength(12))
+        );
+        assert_eq!(
+            truncate::<11>(67895432121, 0),
+            Err(JPMeshError::InvalidCodeLength(0))
+        );
+    }
+
+    #[test]
+    fn test_to_number_length() {
+        let code = CodeNum::<11, 0>::from_number(67895432121).unwrap();
+        assert_eq!(code.to_number(11), Ok(67895432121));
+        assert_eq!(code.to_number(4), Ok(6789));
+        assert_eq!(code.to_number(0), Err(JPMeshError::InvalidCodeLength(0)));
+        assert_eq!(
+            code.to_number(usize::MAX),
+            Err(JPMeshError::InvalidCodeLength(usize::MAX))
+        );
+
+        let code = CodeNum::<9, 0>::from_number(533946005).unwrap();
+        assert_eq!(code.to_number(9), Ok(533946005));
+        assert_eq!(code.to_number(10), Err(JPMeshError::InvalidCodeLength(10)));
     }
 }
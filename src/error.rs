@@ -0,0 +1,81 @@
+use std::fmt;
+
+/// 地域メッシュの処理で発生するエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JPMeshError {
+    /// メッシュコードに数字以外の文字が含まれています。
+    InvalidFormat(String),
+    /// メッシュコードの桁数が、対応するどの地域メッシュとも一致しません。
+    InvalidCodeLength(usize),
+    /// メッシュコードの桁に、取りえない値が含まれています。(桁の位置は先頭を0とします)
+    InvalidDigit { position: usize, digit: u8 },
+    /// 矩形の最小座標が最大座標を超えています。
+    InvertedRect,
+    /// メッシュの種類として解釈できません。
+    UnknownMeshType(String),
+    /// WKT として解釈できません。
+    InvalidWkt(String),
+    /// GeoJSON から地域メッシュを読み取れません。
+    InvalidGeoJson(String),
+    /// GeoJSON の Feature のプロパティを JSON のオブジェクトとしてシリアライズできません。
+    InvalidProperties(String),
+    /// 第1次地域区画が日本の範囲に含まれません。(第1次地域区画のメッシュコード)
+    UndefinedPrimaryMesh(u64),
+    /// 座標が、地域メッシュを定義できる範囲の外にあります。
+    OutOfDomain,
+}
+
+impl fmt::Display for JPMeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JPMeshError::InvalidFormat(s) => {
+                write!(f, "メッシュコードとして解釈できません: {:?}", s)
+            }
+            JPMeshError::InvalidCodeLength(length) => {
+                write!(f, "メッシュコードの桁数が不正です: {}桁", length)
+            }
+            JPMeshError::InvalidDigit { position, digit } => {
+                write!(
+                    f,
+                    "メッシュコードの{}桁目の値が不正です: {}",
+                    position + 1,
+                    digit
+                )
+            }
+            JPMeshError::InvertedRect => {
+                write!(f, "矩形の最小座標が最大座標を超えています")
+            }
+            JPMeshError::UnknownMeshType(s) => {
+                write!(
+                    f,
+                    "メッシュの種類として解釈できません: {:?} (80km, 10km, 5km, 2km, 1km, 500m, 250m, 125m のいずれかを指定してください)",
+                    s
+                )
+            }
+            JPMeshError::InvalidWkt(s) => {
+                write!(f, "WKT の POINT として解釈できません: {:?}", s)
+            }
+            JPMeshError::InvalidGeoJson(reason) => {
+                write!(f, "GeoJSON から地域メッシュを読み取れません: {}", reason)
+            }
+            JPMeshError::InvalidProperties(reason) => {
+                write!(
+                    f,
+                    "プロパティを JSON のオブジェクトとしてシリアライズできません: {}",
+                    reason
+                )
+            }
+            JPMeshError::UndefinedPrimaryMesh(primary) => {
+                write!(f, "第1次地域区画 {:04} は日本の範囲に含まれません", primary)
+            }
+            JPMeshError::OutOfDomain => {
+                write!(
+                    f,
+                    "座標が地域メッシュを定義できる範囲 (経度100度〜180度、緯度0度〜66度40分) の外にあります"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for JPMeshError {}
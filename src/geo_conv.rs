@@ -0,0 +1,74 @@
+//! geo-types の型との変換 (`geo` feature)
+//!
+//! geo-types では x を経度、y を緯度として扱います。
+
+use crate::{Coordinates, JPMesh, Rect};
+
+impl From<Coordinates> for geo_types::Coord<f64> {
+    fn from(coords: Coordinates) -> Self {
+        geo_types::coord! { x: coords.lng, y: coords.lat }
+    }
+}
+
+impl From<Coordinates> for geo_types::Point<f64> {
+    fn from(coords: Coordinates) -> Self {
+        geo_types::Point::new(coords.lng, coords.lat)
+    }
+}
+
+impl From<Rect> for geo_types::Rect<f64> {
+    fn from(rect: Rect) -> Self {
+        geo_types::Rect::new(rect.min(), rect.max())
+    }
+}
+
+impl JPMesh {
+    /// 地域メッシュの範囲を geo-types の `Rect` (x: 経度, y: 緯度) として取得します。
+    pub fn to_geo_rect(&self) -> geo_types::Rect<f64> {
+        self.to_bounds().into()
+    }
+
+    /// 地域メッシュの範囲を geo-types の `Polygon` (x: 経度, y: 緯度) として取得します。
+    ///
+    /// 外周は南西端から始まる反時計回りの閉じた頂点列です。
+    pub fn to_geo_polygon(&self) -> geo_types::Polygon<f64> {
+        let ring = self
+            .to_bounds()
+            .as_polygon(false)
+            .into_iter()
+            .map(geo_types::Coord::from)
+            .collect::<Vec<_>>();
+        geo_types::Polygon::new(ring.into(), vec![])
+    }
+
+    /// 地域メッシュの中心を geo-types の `Point` (x: 経度, y: 緯度) として取得します。
+    pub fn centroid(&self) -> geo_types::Point<f64> {
+        self.to_bounds().center().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JPMesh, JPMeshType};
+
+    #[test]
+    fn test_to_geo_polygon() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+
+        let polygon = mesh.to_geo_polygon();
+        let exterior = polygon.exterior();
+        assert!(exterior.is_closed());
+        assert_eq!(exterior.0.len(), 5);
+        assert_eq!(exterior.0[0].x, bounds.min().lng);
+        assert_eq!(exterior.0[0].y, bounds.min().lat);
+
+        let rect = mesh.to_geo_rect();
+        assert_eq!(rect.min().x, bounds.min().lng);
+        assert_eq!(rect.max().y, bounds.max().lat);
+
+        let centroid = mesh.centroid();
+        assert_eq!(centroid.x(), bounds.center().lng);
+        assert_eq!(centroid.y(), bounds.center().lat);
+    }
+}
@@ -0,0 +1,131 @@
+use serde_json::{Map, Value, json};
+
+use crate::{Coordinates, JPMesh, JPMeshError, JPMeshType, Rect};
+
+/// GeoJSON の出力に関する設定
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GeoJsonOptions {
+    /// 各 Feature と FeatureCollection に、範囲を表す `bbox` (`[最小経度, 最小緯度, 最大経度, 最大緯度]`) を含めるかどうか
+    pub bbox: bool,
+}
+
+/// 矩形を GeoJSON の bbox (`[最小経度, 最小緯度, 最大経度, 最大緯度]`) に変換します。
+fn rect_to_bbox(rect: Rect) -> Value {
+    json!([
+        rect.min().lng,
+        rect.min().lat,
+        rect.max().lng,
+        rect.max().lat
+    ])
+}
+
+/// 矩形を GeoJSON の Polygon の座標列 (反時計回り) に変換します。
+pub(crate) fn rect_to_polygon(rect: Rect) -> Value {
+    let ring: Vec<[f64; 2]> = rect
+        .as_polygon(false)
+        .iter()
+        .map(|coords| coords.as_array())
+        .collect();
+
+    json!([ring])
+}
+
+/// 矩形を GeoJSON の Polygon のジオメトリに変換します。
+pub(crate) fn rect_to_geometry(rect: Rect) -> Value {
+    json!({
+        "type": "Polygon",
+        "coordinates": rect_to_polygon(rect),
+    })
+}
+
+/// 地域メッシュを、メッシュコードを `mesh_code` プロパティに持つ GeoJSON の Feature に変換します。
+pub(crate) fn mesh_to_feature(mesh: &JPMesh, options: &GeoJsonOptions) -> Value {
+    mesh_to_feature_with(mesh, Map::new(), options)
+}
+
+/// 地域メッシュを、指定されたプロパティとメッシュコードの `mesh_code` プロパティを持つ GeoJSON の Feature に変換します。
+///
+/// プロパティに `mesh_code` が含まれる場合は、メッシュコードで上書きします。
+pub(crate) fn mesh_to_feature_with(
+    mesh: &JPMesh,
+    mut properties: Map<String, Value>,
+    options: &GeoJsonOptions,
+) -> Value {
+    properties.insert("mesh_code".to_string(), json!(mesh.to_number()));
+    let bounds = mesh.to_bounds();
+    let mut feature = json!({
+        "type": "Feature",
+        "properties": properties,
+        "geometry": rect_to_geometry(bounds),
+    });
+    if options.bbox {
+        feature["bbox"] = rect_to_bbox(bounds);
+    }
+    feature
+}
+
+/// GeoJSON の Feature の列を FeatureCollection にまとめます。
+///
+/// `bbox` を指定した場合は、FeatureCollection の `bbox` として出力します。
+pub(crate) fn feature_collection(features: Vec<Value>, bbox: Option<Rect>) -> Value {
+    let mut collection = json!({
+        "type": "FeatureCollection",
+        "features": features,
+    });
+    if let Some(bbox) = bbox {
+        collection["bbox"] = rect_to_bbox(bbox);
+    }
+    collection
+}
+
+/// GeoJSON の Feature から地域メッシュを読み取ります。
+///
+/// `mesh_code` プロパティ (数値または文字列) があればメッシュコードとして解釈し、
+/// なければ Polygon の外周の範囲の大きさから種類を、中心から地域メッシュを求めます。
+pub(crate) fn feature_to_mesh(feature: &Value) -> Result<JPMesh, JPMeshError> {
+    match &feature["properties"]["mesh_code"] {
+        Value::Number(number) => return number.to_string().parse(),
+        Value::String(code) => return code.parse(),
+        Value::Null => {}
+        other => {
+            return Err(JPMeshError::InvalidGeoJson(format!(
+                "mesh_code は数値または文字列である必要があります: {}",
+                other
+            )));
+        }
+    }
+
+    let invalid = || JPMeshError::InvalidGeoJson("Polygon の座標を読み取れません".to_string());
+    let ring = feature["geometry"]["coordinates"][0]
+        .as_array()
+        .ok_or_else(invalid)?;
+    let extent = ring
+        .iter()
+        .map(
+            |position| match (position[0].as_f64(), position[1].as_f64()) {
+                (Some(lng), Some(lat)) => Ok(Coordinates::new(lng, lat)),
+                _ => Err(invalid()),
+            },
+        )
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .collect::<Option<Rect>>()
+        .ok_or_else(invalid)?;
+
+    let width = extent.max().lng - extent.min().lng;
+    let height = extent.max().lat - extent.min().lat;
+    let mesh_type = JPMeshType::all()
+        .into_iter()
+        .find(|mesh_type| {
+            (width - mesh_type.lng_interval()).abs() < 1e-9
+                && (height - mesh_type.lat_interval()).abs() < 1e-9
+        })
+        .ok_or_else(|| {
+            JPMeshError::InvalidGeoJson(format!(
+                "Polygon の大きさがどの地域メッシュとも一致しません: {} x {}",
+                width, height
+            ))
+        })?;
+
+    Ok(JPMesh::from_center(extent.center(), mesh_type))
+}
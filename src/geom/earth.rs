@@ -0,0 +1,49 @@
+//! 度とメートルの換算
+//!
+//! 地球を半径 [`EARTH_RADIUS_METERS`] の球とみなして換算します。
+//! 経度1度あたりの距離は緯度によって cos(緯度) 倍に縮むため、経度方向の換算には必ず緯度を指定します。
+//! 楕円体による違いは最大で 1% 程度であり、メッシュの大きさの目安としては十分な精度です。
+
+use std::f64::consts::PI;
+
+/// 地球の半径 (メートル)。WGS84 の赤道半径を用います。
+pub const EARTH_RADIUS_METERS: f64 = 6_378_137.0;
+
+/// 緯度1度あたりの距離 (メートル) を取得します。
+pub fn meters_per_deg_lat() -> f64 {
+    EARTH_RADIUS_METERS * PI / 180.0
+}
+
+/// 指定された緯度 (度) における、経度1度あたりの距離 (メートル) を取得します。
+pub fn meters_per_deg_lng(lat: f64) -> f64 {
+    meters_per_deg_lat() * lat.to_radians().cos()
+}
+
+/// 1メートルあたりの緯度の差 (度) を取得します。
+pub fn deg_lat_per_meter() -> f64 {
+    1.0 / meters_per_deg_lat()
+}
+
+/// 指定された緯度 (度) における、1メートルあたりの経度の差 (度) を取得します。
+///
+/// 極 (緯度 ±90度) では無限大となります。
+pub fn deg_lng_per_meter(lat: f64) -> f64 {
+    1.0 / meters_per_deg_lng(lat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meters_per_deg() {
+        assert!((meters_per_deg_lat() - 111_319.49).abs() < 0.01);
+        assert_eq!(meters_per_deg_lng(0.0), meters_per_deg_lat());
+        assert!((meters_per_deg_lng(60.0) - meters_per_deg_lat() / 2.0).abs() < 1e-6);
+
+        for lat in [0.0, 20.0, 35.681236, 45.5] {
+            assert!((meters_per_deg_lng(lat) * deg_lng_per_meter(lat) - 1.0).abs() < 1e-12);
+        }
+        assert!((meters_per_deg_lat() * deg_lat_per_meter() - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,722 @@
+use crate::{JPMesh, JPMeshError, JPMeshType, mesh_type::mesh_domain};
+
+pub mod earth;
+
+/// 座標の地理座標系として想定する SRID (EPSG:4326、WGS84 の経緯度)
+///
+/// 地域メッシュは日本測地系2011 (JGD2011、EPSG:6668) の経緯度で定義されますが、
+/// 地域メッシュの精度では WGS84 との差は無視できるため、JGD2000・JGD2011 の座標はそのまま使用できます。
+/// 旧日本測地系 (Tokyo Datum、EPSG:4301) の座標は数百メートルずれた地域メッシュとなるため、事前に変換してください。
+pub const JPMESH_SRID: u32 = 4326;
+
+/// 座標を表す構造体
+///
+/// 経度・緯度を `f64` で保持するため、`PartialEq` のみを実装し、意図的に `Eq`・`Hash` は実装していません。
+/// (NaN が自身と等しくならないほか、計算誤差によってわずかに異なる値が別の座標となるため)
+/// `HashMap` のキーや重複の除去には [`Coordinates::to_fixed`] で整数の組に変換した値を使用してください。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    /// 経度
+    pub lng: f64,
+    /// 緯度
+    pub lat: f64,
+}
+
+impl Coordinates {
+    /// 指定された経度と緯度を持つ座標を生成します。
+    pub const fn new(lng: f64, lat: f64) -> Self {
+        Self { lng, lat }
+    }
+
+    /// ラジアンで表された経度と緯度から座標を生成します。
+    ///
+    /// 度に変換して保持します。ラジアンの値をそのまま [`Coordinates::new`] に渡すと、
+    /// 地域メッシュを定義できる範囲の外の座標となるため、[`JPMesh::try_new`](crate::JPMesh::try_new) では
+    /// [`JPMeshError::OutOfDomain`] となります。
+    pub fn from_radians(lng_rad: f64, lat_rad: f64) -> Self {
+        Self::new(lng_rad.to_degrees(), lat_rad.to_degrees())
+    }
+
+    /// 座標を、ラジアンで表された `(経度, 緯度)` として取得します。
+    pub fn to_radians(&self) -> (f64, f64) {
+        (self.lng.to_radians(), self.lat.to_radians())
+    }
+
+    /// 経度と緯度を小数点以下 `decimals` 桁で丸め、`10^decimals` 倍した整数の組 `(経度, 緯度)` として取得します。
+    ///
+    /// 同じ値に丸められる座標は同じ組になるため、`HashMap` のキーや重複の除去に使用できます。
+    /// 整数の範囲を超える値は `i64` の最小値・最大値に、NaN は0になります。
+    pub fn to_fixed(&self, decimals: u32) -> (i64, i64) {
+        let scale = 10f64.powi(decimals as i32);
+        (
+            (self.lng * scale).round() as i64,
+            (self.lat * scale).round() as i64,
+        )
+    }
+
+    /// 座標を `[経度, 緯度]` の配列として取得します。
+    pub fn as_array(&self) -> [f64; 2] {
+        [self.lng, self.lat]
+    }
+
+    /// 座標を地域メッシュを定義できる範囲に収めた座標を取得します。
+    ///
+    /// 範囲の外にある座標は、範囲の端にある地域メッシュに含まれる座標になります。
+    /// 範囲の東端と北端は範囲に含まれないため、そのすぐ内側の値に収めます。
+    /// 経度または緯度が NaN の場合、その値は NaN のままです。
+    pub fn clamp_to_mesh_domain(&self) -> Coordinates {
+        let domain = mesh_domain();
+        let (min, max) = (domain.min(), domain.max());
+        Self::new(
+            self.lng.clamp(min.lng, max.lng.next_down()),
+            self.lat.clamp(min.lat, max.lat.next_down()),
+        )
+    }
+
+    /// WKT の2次元の点 (例: `POINT(139.76 35.68)`) から座標を生成します。
+    ///
+    /// 座標は経度, 緯度の順に解釈します。`POINT` の大文字・小文字や、括弧の前後の空白は問いません。
+    /// `POINT Z` などの3次元以上の点や、座標の数が2つでない場合は [`JPMeshError::InvalidWkt`] を返します。
+    pub fn from_wkt_point(s: &str) -> Result<Coordinates, JPMeshError> {
+        let invalid = || JPMeshError::InvalidWkt(s.to_string());
+
+        let trimmed = s.trim();
+        let rest = trimmed
+            .get(..5)
+            .filter(|keyword| keyword.eq_ignore_ascii_case("POINT"))
+            .map(|_| trimmed[5..].trim_start())
+            .ok_or_else(invalid)?;
+        let body = rest
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(invalid)?;
+
+        let ordinates = body
+            .split_whitespace()
+            .map(|token| token.parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+            .map_err(|_| invalid())?;
+        match ordinates[..] {
+            [lng, lat] => Ok(Self::new(lng, lat)),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// 2つの座標の中点を取得します。
+    ///
+    /// 経度と緯度をそれぞれ平均した平面上の中点であり、測地線上の中点ではありません。
+    pub fn midpoint(&self, other: &Coordinates) -> Coordinates {
+        Self::new((self.lng + other.lng) / 2.0, (self.lat + other.lat) / 2.0)
+    }
+
+    /// 経度と緯度を指定された小数点以下の桁数に丸めた座標を取得します。
+    pub(crate) fn rounded(&self, decimals: u32) -> Self {
+        let scale = 10f64.powi(decimals as i32);
+        Self::new(
+            (self.lng * scale).round() / scale,
+            (self.lat * scale).round() / scale,
+        )
+    }
+}
+
+/// `(経度, 緯度)` の順のタプルから座標を生成します。
+///
+/// 引数の順序は [`Coordinates::new`] と同じです。緯度が先のデータには [`LatLng`] を使用してください。
+impl From<(f64, f64)> for Coordinates {
+    fn from((lng, lat): (f64, f64)) -> Self {
+        Self::new(lng, lat)
+    }
+}
+
+/// 緯度, 経度の順に値を持つ座標
+///
+/// 緯度が先に並ぶデータを、軸の順序を取り違えずに [`Coordinates`] へ変換するために使用します。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatLng(pub f64, pub f64);
+
+impl From<LatLng> for Coordinates {
+    fn from(LatLng(lat, lng): LatLng) -> Self {
+        Self::new(lng, lat)
+    }
+}
+
+impl From<Coordinates> for LatLng {
+    fn from(coords: Coordinates) -> Self {
+        LatLng(coords.lat, coords.lng)
+    }
+}
+
+/// 矩形を表す構造体
+///
+/// 範囲は南西端 (最小座標) を含み、北東端 (最大座標) を含まない半開区間 `[min, max)` として扱います。
+/// これにより、隣接する地域メッシュの境界上の座標は、東側・北側の地域メッシュのみに含まれます。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    min_coord: Coordinates,
+    max_coord: Coordinates,
+}
+
+impl Rect {
+    /// 指定された座標を含む矩形を生成します。
+    ///
+    /// `min_coord` の経度と緯度は、それぞれ `max_coord` 以下である必要があります。
+    /// この条件は検査しないため、入力が不確かな場合は [`Rect::try_new`] を使用してください。
+    pub const fn new(min_coord: Coordinates, max_coord: Coordinates) -> Self {
+        Self {
+            min_coord,
+            max_coord,
+        }
+    }
+
+    /// 指定された座標を含む矩形を生成します。
+    ///
+    /// `min_coord` の経度または緯度が `max_coord` を超える場合 (NaN を含む場合を含む) は
+    /// [`JPMeshError::InvertedRect`] を返します。
+    pub fn try_new(min_coord: Coordinates, max_coord: Coordinates) -> Result<Self, JPMeshError> {
+        if min_coord.lng <= max_coord.lng && min_coord.lat <= max_coord.lat {
+            Ok(Self::new(min_coord, max_coord))
+        } else {
+            Err(JPMeshError::InvertedRect)
+        }
+    }
+
+    /// 最小座標と最大座標を経度と緯度ごとに入れ替え、正しい向きにした矩形を取得します。
+    pub fn normalized(&self) -> Rect {
+        let (a, b) = (self.min_coord, self.max_coord);
+        Rect::new(
+            Coordinates::new(a.lng.min(b.lng), a.lat.min(b.lat)),
+            Coordinates::new(a.lng.max(b.lng), a.lat.max(b.lat)),
+        )
+    }
+
+    /// 矩形の最小座標を取得します。
+    pub fn min(&self) -> Coordinates {
+        self.min_coord
+    }
+
+    /// 矩形の最大座標を取得します。
+    pub fn max(&self) -> Coordinates {
+        self.max_coord
+    }
+
+    /// 矩形の中心座標を取得します。
+    pub fn center(&self) -> Coordinates {
+        Coordinates::new(
+            (self.min_coord.lng + self.max_coord.lng) / 2.0,
+            (self.min_coord.lat + self.max_coord.lat) / 2.0,
+        )
+    }
+
+    /// 矩形を `(最小経度, 最小緯度, 最大経度, 最大緯度)` のタプルとして取得します。
+    ///
+    /// 順序は GeoJSON の bbox と同じです。
+    pub fn to_tuple(&self) -> (f64, f64, f64, f64) {
+        (
+            self.min_coord.lng,
+            self.min_coord.lat,
+            self.max_coord.lng,
+            self.max_coord.lat,
+        )
+    }
+
+    /// `(最小経度, 最小緯度, 最大経度, 最大緯度)` のタプルから矩形を生成します。
+    ///
+    /// 順序は GeoJSON の bbox と同じです。
+    pub fn from_tuple((min_lng, min_lat, max_lng, max_lat): (f64, f64, f64, f64)) -> Rect {
+        Rect::new(
+            Coordinates::new(min_lng, min_lat),
+            Coordinates::new(max_lng, max_lat),
+        )
+    }
+
+    /// 矩形を経度方向に `cols`、緯度方向に `rows` 等分した各区画の中心の座標を取得します。
+    ///
+    /// 南の行から順に、各行の西から東へ向かって並びます。`cols` または `rows` が0の場合は空の列を返します。
+    pub fn sample_grid(&self, cols: u32, rows: u32) -> Vec<Coordinates> {
+        let min = self.min();
+        let max = self.max();
+        let lng_step = (max.lng - min.lng) / cols as f64;
+        let lat_step = (max.lat - min.lat) / rows as f64;
+
+        (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (col, row)))
+            .map(|(col, row)| {
+                Coordinates::new(
+                    min.lng + (col as f64 + 0.5) * lng_step,
+                    min.lat + (row as f64 + 0.5) * lat_step,
+                )
+            })
+            .collect()
+    }
+
+    /// 指定された座標が矩形に含まれるかどうかを判定します。
+    ///
+    /// 最小座標の辺上の座標は含み、最大座標の辺上の座標は含みません。
+    pub fn includes(&self, coords: Coordinates) -> bool {
+        let min = self.min();
+        let max = self.max();
+
+        coords.lat >= min.lat
+            && coords.lat < max.lat
+            && coords.lng >= min.lng
+            && coords.lng < max.lng
+    }
+
+    /// 矩形の4つの頂点を、南西から反時計回りに返すイテレータを取得します。
+    pub fn iter_corners(&self) -> impl Iterator<Item = Coordinates> + use<> {
+        let min = self.min_coord;
+        let max = self.max_coord;
+
+        [
+            min,
+            Coordinates::new(max.lng, min.lat),
+            max,
+            Coordinates::new(min.lng, max.lat),
+        ]
+        .into_iter()
+    }
+
+    /// 矩形を、始点と終点が一致する閉じた頂点列として取得します。
+    ///
+    /// 始点は南西の頂点です。`clockwise` が `true` の場合は時計回り、`false` の場合は反時計回り (GeoJSON の外周と同じ向き) となります。
+    pub fn as_polygon(&self, clockwise: bool) -> Vec<Coordinates> {
+        let mut ring: Vec<Coordinates> = self.iter_corners().collect();
+        if clockwise {
+            ring[1..].reverse();
+        }
+        ring.push(ring[0]);
+        ring
+    }
+
+    /// 2つの矩形をともに含む最小の矩形を取得します。
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::new(
+            Coordinates::new(
+                self.min_coord.lng.min(other.min_coord.lng),
+                self.min_coord.lat.min(other.min_coord.lat),
+            ),
+            Coordinates::new(
+                self.max_coord.lng.max(other.max_coord.lng),
+                self.max_coord.lat.max(other.max_coord.lat),
+            ),
+        )
+    }
+
+    /// 矩形と地域メッシュの範囲をともに含む最小の矩形を取得します。([`Rect::union`] を参照)
+    pub fn extend_to_cell(&self, mesh: &JPMesh) -> Rect {
+        self.union(&mesh.to_bounds())
+    }
+
+    /// 2つの矩形の共通部分を取得します。
+    ///
+    /// 共通部分の面積が0となる場合は `None` を返します。
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Coordinates::new(
+            self.min_coord.lng.max(other.min_coord.lng),
+            self.min_coord.lat.max(other.min_coord.lat),
+        );
+        let max = Coordinates::new(
+            self.max_coord.lng.min(other.max_coord.lng),
+            self.max_coord.lat.min(other.max_coord.lat),
+        );
+
+        if min.lng < max.lng && min.lat < max.lat {
+            Some(Rect::new(min, max))
+        } else {
+            None
+        }
+    }
+
+    /// 矩形を、メッシュコードで表現できる範囲 (経度100度〜180度、緯度0度〜66度40分) に切り詰めます。
+    ///
+    /// 範囲と重ならない場合は `None` を返します。
+    pub fn clamp_to_mesh_domain(&self) -> Option<Rect> {
+        self.intersection(&mesh_domain())
+    }
+
+    /// 矩形と重なる、指定された種類の地域メッシュの格子上の位置の範囲を取得します。((最小の列, 最小の行), (最大の列, 最大の行))
+    ///
+    /// 範囲は両端を含み、位置は [`JPMesh::grid_index`](crate::JPMesh::grid_index) と同じ格子全体での値です。
+    /// 対象の地域メッシュは [`JPMesh::from_on_bounds`](crate::JPMesh::from_on_bounds) と同じで、
+    /// 大きさ `(最大の列 - 最小の列 + 1) * (最大の行 - 最小の行 + 1)` の配列に、各地域メッシュを格子上の位置と最小の位置との差で配置できます。
+    /// メッシュコードで表現できる範囲と重ならない場合や、矩形の向きが逆の場合は `None` を返します。
+    pub fn grid_index_range(&self, mesh_type: JPMeshType) -> Option<((u64, u64), (u64, u64))> {
+        mesh_type.grid_index_range(self)
+    }
+
+    /// 矩形の東西方向の幅を取得します。(メートル)
+    ///
+    /// 経度1度あたりの距離は矩形の中心緯度における値で近似します。([`earth::meters_per_deg_lng`] を参照)
+    pub fn width_meters(&self) -> f64 {
+        (self.max_coord.lng - self.min_coord.lng) * earth::meters_per_deg_lng(self.center().lat)
+    }
+
+    /// 矩形の南北方向の高さを取得します。(メートル)
+    pub fn height_meters(&self) -> f64 {
+        (self.max_coord.lat - self.min_coord.lat) * earth::meters_per_deg_lat()
+    }
+}
+
+/// 座標の列から、すべての座標を含む最小の矩形を生成します。
+///
+/// 座標が1つもない場合は `None` となります。
+/// 経度または緯度が NaN の座標は無視します。
+impl FromIterator<Coordinates> for Option<Rect> {
+    fn from_iter<I: IntoIterator<Item = Coordinates>>(iter: I) -> Self {
+        iter.into_iter()
+            .filter(|coords| !coords.lng.is_nan() && !coords.lat.is_nan())
+            .fold(None, |rect, coords| match rect {
+                None => Some(Rect::new(coords, coords)),
+                Some(rect) => Some(Rect::new(
+                    Coordinates::new(
+                        rect.min_coord.lng.min(coords.lng),
+                        rect.min_coord.lat.min(coords.lat),
+                    ),
+                    Coordinates::new(
+                        rect.max_coord.lng.max(coords.lng),
+                        rect.max_coord.lat.max(coords.lat),
+                    ),
+                )),
+            })
+    }
+}
+
+/// 座標の列の重心 (経度と緯度それぞれの算術平均) を取得します。
+///
+/// 座標が1つもない場合は `None` を返します。
+pub fn centroid(points: &[Coordinates]) -> Option<Coordinates> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let count = points.len() as f64;
+    let (lng, lat) = points
+        .iter()
+        .fold((0.0, 0.0), |(lng, lat), p| (lng + p.lng, lat + p.lat));
+    Some(Coordinates::new(lng / count, lat / count))
+}
+
+/// 座標が頂点列で表される多角形の内部にあるかどうかを、偶奇規則で判定します。
+///
+/// 頂点列は閉じていても (始点を末尾に含んでいても) 閉じていなくても構いません。
+pub(crate) fn ring_contains(ring: &[Coordinates], coords: Coordinates) -> bool {
+    let Some(&last) = ring.last() else {
+        return false;
+    };
+
+    let mut inside = false;
+    let mut prev = last;
+    for &vertex in ring {
+        if (vertex.lat > coords.lat) != (prev.lat > coords.lat) {
+            let lng = vertex.lng
+                + (coords.lat - vertex.lat) / (prev.lat - vertex.lat) * (prev.lng - vertex.lng);
+            if coords.lng < lng {
+                inside = !inside;
+            }
+        }
+        prev = vertex;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_coordinates_radians() {
+        let coords =
+            Coordinates::from_radians(139.767125f64.to_radians(), 35.681236f64.to_radians());
+        assert!((coords.lng - 139.767125).abs() < 1e-12);
+        assert!((coords.lat - 35.681236).abs() < 1e-12);
+
+        let (lng, lat) = coords.to_radians();
+        assert!((lng - 139.767125f64.to_radians()).abs() < 1e-15);
+        assert!((lat - 35.681236f64.to_radians()).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_rect_grid_index_range() {
+        use crate::JPMesh;
+
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let (col, row) = mesh.grid_index();
+        let far = JPMesh::from_grid_index(col + 3, row + 2, JPMeshType::Mesh1km).unwrap();
+        let rect = Rect::new(mesh.to_bounds().center(), far.to_bounds().center());
+
+        let ((min_col, min_row), (max_col, max_row)) =
+            rect.grid_index_range(JPMeshType::Mesh1km).unwrap();
+        assert_eq!(
+            ((min_col, min_row), (max_col, max_row)),
+            ((col, row), (col + 3, row + 2))
+        );
+
+        // 各地域メッシュを配列に配置できる
+        let (width, height) = (max_col - min_col + 1, max_row - min_row + 1);
+        let mut cells = vec![None; (width * height) as usize];
+        for mesh in JPMesh::from_on_bounds(rect, JPMeshType::Mesh1km) {
+            let (col, row) = mesh.grid_index();
+            cells[((row - min_row) * width + (col - min_col)) as usize] = Some(mesh);
+        }
+        assert!(cells.iter().all(Option::is_some));
+
+        let outside = Rect::new(Coordinates::new(10.0, 10.0), Coordinates::new(20.0, 20.0));
+        assert_eq!(outside.grid_index_range(JPMeshType::Mesh1km), None);
+    }
+
+    #[test]
+    fn test_rect_clamp_to_mesh_domain() {
+        let rect = Rect::new(Coordinates::new(90.0, -10.0), Coordinates::new(140.0, 40.0));
+        let clamped = rect.clamp_to_mesh_domain().unwrap();
+        assert_eq!(clamped.min(), Coordinates::new(100.0, 0.0));
+        assert_eq!(clamped.max(), Coordinates::new(140.0, 40.0));
+
+        let rect = Rect::new(Coordinates::new(170.0, 60.0), Coordinates::new(200.0, 80.0));
+        let clamped = rect.clamp_to_mesh_domain().unwrap();
+        assert_eq!(clamped.min(), Coordinates::new(170.0, 60.0));
+        assert_eq!(clamped.max().lng, 180.0);
+        assert!((clamped.max().lat - 200.0 / 3.0).abs() < 1e-9);
+
+        let rect = Rect::new(Coordinates::new(-10.0, 0.0), Coordinates::new(10.0, 10.0));
+        assert_eq!(rect.clamp_to_mesh_domain(), None);
+    }
+
+    #[test]
+    fn test_coordinates_clamp_to_mesh_domain() {
+        let domain = mesh_domain();
+
+        let inside = Coordinates::new(139.767125, 35.681236);
+        assert_eq!(inside.clamp_to_mesh_domain(), inside);
+
+        for coords in [
+            Coordinates::new(99.0, -1.0),
+            Coordinates::new(180.0, 66.7),
+            Coordinates::new(f64::INFINITY, f64::NEG_INFINITY),
+        ] {
+            assert!(domain.includes(coords.clamp_to_mesh_domain()));
+        }
+
+        let clamped = Coordinates::new(f64::NAN, 35.0).clamp_to_mesh_domain();
+        assert!(clamped.lng.is_nan());
+        assert_eq!(clamped.lat, 35.0);
+    }
+
+    #[test]
+    fn test_coordinates_from_tuple() {
+        let coords = Coordinates::from((139.767125, 35.681236));
+        assert_eq!(coords, Coordinates::new(139.767125, 35.681236));
+
+        let coords = Coordinates::from(LatLng(35.681236, 139.767125));
+        assert_eq!(coords, Coordinates::new(139.767125, 35.681236));
+        assert_eq!(LatLng::from(coords), LatLng(35.681236, 139.767125));
+    }
+
+    #[test]
+    fn test_rect_try_new() {
+        let min = Coordinates::new(139.0, 35.0);
+        let max = Coordinates::new(140.0, 36.0);
+        assert_eq!(Rect::try_new(min, max), Ok(Rect::new(min, max)));
+
+        let inverted = Rect::new(Coordinates::new(140.0, 35.0), Coordinates::new(139.0, 36.0));
+        assert_eq!(
+            Rect::try_new(inverted.min(), inverted.max()),
+            Err(JPMeshError::InvertedRect)
+        );
+        assert_eq!(inverted.normalized(), Rect::new(min, max));
+        assert_eq!(Rect::new(max, min).normalized(), Rect::new(min, max));
+    }
+
+    #[test]
+    fn test_coordinates_from_wkt_point() {
+        let expected = Ok(Coordinates::new(139.76, 35.68));
+        assert_eq!(Coordinates::from_wkt_point("POINT(139.76 35.68)"), expected);
+        assert_eq!(
+            Coordinates::from_wkt_point(" point ( 139.76  35.68 ) "),
+            expected
+        );
+
+        for invalid in [
+            "POINT Z (139.76 35.68 10)",
+            "POINT(139.76 35.68 10)",
+            "POINT(139.76)",
+            "POINT(139.76, 35.68)",
+            "LINESTRING(139.76 35.68)",
+            "POINT 139.76 35.68",
+            "",
+        ] {
+            assert_eq!(
+                Coordinates::from_wkt_point(invalid),
+                Err(JPMeshError::InvalidWkt(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_rect_tuple() {
+        let rect = Rect::new(Coordinates::new(139.0, 35.0), Coordinates::new(140.0, 36.0));
+        assert_eq!(rect.to_tuple(), (139.0, 35.0, 140.0, 36.0));
+        assert_eq!(Rect::from_tuple(rect.to_tuple()), rect);
+    }
+
+    #[test]
+    fn test_coordinates_midpoint() {
+        let a = Coordinates::new(139.0, 35.0);
+        let b = Coordinates::new(140.0, 36.0);
+        assert_eq!(a.midpoint(&b), Coordinates::new(139.5, 35.5));
+        assert_eq!(a.midpoint(&a), a);
+    }
+
+    #[test]
+    fn test_rect_sample_grid() {
+        let rect = Rect::new(Coordinates::new(139.0, 35.0), Coordinates::new(140.0, 36.0));
+
+        let points = rect.sample_grid(2, 2);
+        assert_eq!(
+            points,
+            vec![
+                Coordinates::new(139.25, 35.25),
+                Coordinates::new(139.75, 35.25),
+                Coordinates::new(139.25, 35.75),
+                Coordinates::new(139.75, 35.75),
+            ]
+        );
+
+        let points = rect.sample_grid(10, 3);
+        assert_eq!(points.len(), 30);
+        assert!(points.iter().all(|&p| rect.includes(p)));
+
+        assert!(rect.sample_grid(0, 3).is_empty());
+    }
+
+    #[test]
+    fn test_centroid() {
+        let points = [
+            Coordinates::new(139.0, 35.0),
+            Coordinates::new(140.0, 35.0),
+            Coordinates::new(140.0, 36.0),
+            Coordinates::new(139.0, 36.0),
+        ];
+        assert_eq!(centroid(&points), Some(Coordinates::new(139.5, 35.5)));
+        assert_eq!(centroid(&[]), None);
+    }
+
+    /// 頂点列の符号付き面積 (反時計回りで正)
+    fn signed_area(ring: &[Coordinates]) -> f64 {
+        ring.windows(2)
+            .map(|w| w[0].lng * w[1].lat - w[1].lng * w[0].lat)
+            .sum::<f64>()
+            / 2.0
+    }
+
+    #[test]
+    fn test_rect_union() {
+        let a = Rect::new(Coordinates::new(139.0, 35.0), Coordinates::new(140.0, 36.0));
+        let b = Rect::new(Coordinates::new(139.5, 34.0), Coordinates::new(141.0, 35.5));
+        let union = a.union(&b);
+        assert_eq!(union.min(), Coordinates::new(139.0, 34.0));
+        assert_eq!(union.max(), Coordinates::new(141.0, 36.0));
+        assert_eq!(union, b.union(&a));
+        assert_eq!(a.union(&a), a);
+    }
+
+    #[test]
+    fn test_rect_extend_to_cell() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+        assert_eq!(bounds.extend_to_cell(&mesh), bounds);
+
+        let east = mesh.shift(2, 0).unwrap();
+        let rect = bounds.extend_to_cell(&east);
+        assert_eq!(rect.min(), bounds.min());
+        assert_eq!(rect.max(), east.to_bounds().max());
+    }
+
+    #[test]
+    fn test_ring_contains() {
+        // 凹型の多角形 (北側中央が欠けた形)
+        let ring = [
+            Coordinates::new(0.0, 0.0),
+            Coordinates::new(3.0, 0.0),
+            Coordinates::new(3.0, 2.0),
+            Coordinates::new(2.0, 2.0),
+            Coordinates::new(2.0, 1.0),
+            Coordinates::new(1.0, 1.0),
+            Coordinates::new(1.0, 2.0),
+            Coordinates::new(0.0, 2.0),
+        ];
+        assert!(ring_contains(&ring, Coordinates::new(0.5, 1.5)));
+        assert!(ring_contains(&ring, Coordinates::new(1.5, 0.5)));
+        assert!(!ring_contains(&ring, Coordinates::new(1.5, 1.5)));
+        assert!(!ring_contains(&ring, Coordinates::new(4.0, 0.5)));
+
+        let mut closed = ring.to_vec();
+        closed.push(ring[0]);
+        assert!(ring_contains(&closed, Coordinates::new(2.5, 1.5)));
+        assert!(!ring_contains(&[], Coordinates::new(0.5, 0.5)));
+    }
+
+    #[test]
+    fn test_coordinates_to_fixed() {
+        let coords = Coordinates::new(139.767125, 35.681236);
+        assert_eq!(coords.to_fixed(6), (139767125, 35681236));
+        assert_eq!(coords.to_fixed(2), (13977, 3568));
+        assert_eq!(coords.to_fixed(0), (140, 36));
+
+        // 丸めると同じになる座標は同じ組になる
+        let nearby = Coordinates::new(139.7671251, 35.6812359);
+        assert_eq!(nearby.to_fixed(6), coords.to_fixed(6));
+        assert_ne!(nearby.to_fixed(7), coords.to_fixed(7));
+
+        let points = [coords, nearby, Coordinates::new(135.0, 35.0)];
+        let unique: HashSet<(i64, i64)> = points.iter().map(|p| p.to_fixed(6)).collect();
+        assert_eq!(unique.len(), 2);
+
+        assert_eq!(Coordinates::new(f64::NAN, -1.5).to_fixed(0), (0, -2));
+    }
+
+    #[test]
+    fn test_rect_as_polygon() {
+        let rect = Rect::new(Coordinates::new(139.0, 35.0), Coordinates::new(140.0, 36.0));
+
+        let ccw = rect.as_polygon(false);
+        assert_eq!(ccw.len(), 5);
+        assert_eq!(ccw.first(), ccw.last());
+        assert_eq!(ccw[0], rect.min());
+        assert!(signed_area(&ccw) > 0.0);
+
+        let cw = rect.as_polygon(true);
+        assert_eq!(cw.len(), 5);
+        assert_eq!(cw.first(), cw.last());
+        assert_eq!(cw[0], rect.min());
+        assert!(signed_area(&cw) < 0.0);
+
+        assert_eq!(rect.iter_corners().count(), 4);
+    }
+
+    #[test]
+    fn test_rect_from_iter() {
+        let points = vec![
+            Coordinates::new(139.7, 35.7),
+            Coordinates::new(139.9, 35.6),
+            Coordinates::new(f64::NAN, 36.0),
+            Coordinates::new(139.8, 35.8),
+        ];
+
+        let rect = points.into_iter().collect::<Option<Rect>>().unwrap();
+        assert_eq!(rect.min(), Coordinates::new(139.7, 35.6));
+        assert_eq!(rect.max(), Coordinates::new(139.9, 35.8));
+    }
+
+    #[test]
+    fn test_rect_from_iter_empty() {
+        let rect = Vec::<Coordinates>::new()
+            .into_iter()
+            .collect::<Option<Rect>>();
+        assert_eq!(rect, None);
+
+        let rect = std::iter::once(Coordinates::new(f64::NAN, f64::NAN)).collect::<Option<Rect>>();
+        assert_eq!(rect, None);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{Coordinates, JPMesh, JPMeshType};
+
+/// 座標のイテレータを地域メッシュのイテレータに変換する拡張 trait
+///
+/// [`crate::prelude`] を読み込むと、`Coordinates` を返す任意のイテレータで使用できます。
+///
+/// # サンプル
+/// ```
+/// use rust_jp_mesh::prelude::*;
+///
+/// let coords = vec![
+///     Coordinates::new(139.767125, 35.681236),
+///     Coordinates::new(135.495951, 34.702485),
+/// ];
+/// let codes: Vec<u64> = coords
+///     .into_iter()
+///     .mesh_codes(JPMeshType::Mesh1km)
+///     .map(|mesh| mesh.to_number())
+///     .collect();
+/// assert_eq!(codes, vec![53394611, 52350349]);
+/// ```
+pub trait MeshCodeIterExt: Iterator<Item = Coordinates> + Sized {
+    /// 各座標を含む、指定された種類の地域メッシュを順に生成します。([`JPMesh::new`] を参照)
+    fn mesh_codes(self, mesh_type: JPMeshType) -> impl Iterator<Item = JPMesh> {
+        self.map(move |coords| JPMesh::new(coords, mesh_type))
+    }
+}
+
+impl<I: Iterator<Item = Coordinates>> MeshCodeIterExt for I {}
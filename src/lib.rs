@@ -1,9 +1,32 @@
 mod calcs;
 mod code_num;
+mod error;
+#[cfg(feature = "geo")]
+mod geo_conv;
+mod geojson;
 mod geom;
+mod iter_ext;
 mod mesh;
+mod mesh_code_str;
+mod mesh_id;
+pub mod mesh_set;
 mod mesh_type;
+mod prefecture;
+pub mod prelude;
+mod primary_mesh;
+#[cfg(feature = "rational")]
+mod rational;
+mod web_tile;
+mod wkb;
 
-pub use geom::{Coordinates, Rect};
-pub use mesh::JPMesh;
+pub use error::JPMeshError;
+pub use geojson::GeoJsonOptions;
+pub use geom::{Coordinates, JPMESH_SRID, LatLng, Rect, centroid, earth};
+pub use iter_ext::MeshCodeIterExt;
+pub use mesh::{
+    AtLevelResult, CellMembership, JPMesh, MeshDecomposition, MeshOptions, Quadrant, TraversalOrder,
+};
+pub use mesh_code_str::MeshCodeStr;
+pub use mesh_id::MeshId;
 pub use mesh_type::JPMeshType;
+pub use web_tile::MAX_WEB_TILE_ZOOM;
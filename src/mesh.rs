@@ -1,8 +1,153 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use serde::Serialize;
+use serde_json::Value;
+
 use crate::{
-    Coordinates, JPMeshType, Rect,
-    calcs::{to_2km::CodeTo2km, to_5km::CodeTo5km, to_125m::CodeTo125m},
+    Coordinates, GeoJsonOptions, JPMESH_SRID, JPMeshError, JPMeshType, MeshId, Rect,
+    calcs::{MeshCodeRepr, to_2km::CodeTo2km, to_5km::CodeTo5km, to_125m::CodeTo125m},
+    code_num::decimal_length,
+    geojson,
+    geom::ring_contains,
+    mesh_type::MAX_CODE_LENGTH,
+    prefecture, primary_mesh, web_tile, wkb,
 };
 
+/// メッシュの種類に対応する内部表現 `$repr` を用いて地域メッシュを生成します。
+macro_rules! build_mesh {
+    ($mesh_type:expr, |$repr:ident| $code:expr) => {{
+        let mesh_type: JPMeshType = $mesh_type;
+        match mesh_type {
+            JPMeshType::Mesh1km
+            | JPMeshType::Mesh500m
+            | JPMeshType::Mesh250m
+            | JPMeshType::Mesh125m => {
+                type $repr = CodeTo125m;
+                JPMesh::To125m {
+                    code: $code,
+                    mesh_type,
+                }
+            }
+            JPMeshType::Mesh2km => {
+                type $repr = CodeTo2km;
+                JPMesh::To2km {
+                    code: $code,
+                    mesh_type,
+                }
+            }
+            JPMeshType::Mesh80km | JPMeshType::Mesh10km | JPMeshType::Mesh5km => {
+                type $repr = CodeTo5km;
+                JPMesh::To5km {
+                    code: $code,
+                    mesh_type,
+                }
+            }
+        }
+    }};
+}
+
+/// 地域メッシュの内部表現 `$code` と種類 `$mesh_type` を用いて処理を行います。
+macro_rules! with_code {
+    ($mesh:expr, |$code:ident, $mesh_type:ident| $body:expr) => {
+        match $mesh {
+            JPMesh::To125m {
+                code: $code,
+                mesh_type: $mesh_type,
+            } => $body,
+            JPMesh::To2km {
+                code: $code,
+                mesh_type: $mesh_type,
+            } => $body,
+            JPMesh::To5km {
+                code: $code,
+                mesh_type: $mesh_type,
+            } => $body,
+        }
+    };
+}
+
+/// 2×2に分割された区画における位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quadrant {
+    /// 南西 (分割番号 1)
+    SouthWest,
+    /// 南東 (分割番号 2)
+    SouthEast,
+    /// 北西 (分割番号 3)
+    NorthWest,
+    /// 北東 (分割番号 4)
+    NorthEast,
+}
+
+/// 矩形範囲に含まれる地域メッシュを走査する順序 ([`JPMesh::iter_on_bounds_ordered`] を参照)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TraversalOrder {
+    /// 南の行から順に、各行を西から東へ走査します。
+    #[default]
+    RowMajor,
+    /// 西の列から順に、各列を南から北へ走査します。
+    ColumnMajor,
+    /// 南の行から順に、最初の行を西から東へ、次の行を東から西へと、行ごとに向きを反転して走査します。
+    ///
+    /// 連続する地域メッシュは常に格子上で隣接します。
+    Serpentine,
+}
+
+/// 座標と地域メッシュの範囲の位置関係 ([`JPMesh::classify`] を参照)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CellMembership {
+    /// 範囲の内部 (境界上を除く)
+    Inside,
+    /// 範囲の境界上 (四辺のいずれか)
+    OnBoundary,
+    /// 範囲の外
+    Outside,
+}
+
+/// [`JPMesh::new_with_options`] で座標から地域メッシュを生成する際の設定
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MeshOptions {
+    /// 地域メッシュの境界とみなす距離 (度)
+    ///
+    /// 境界からこの距離未満にある座標は、境界の南西側の地域メッシュに含まれるものとして扱います。
+    /// 0 の場合は [`JPMesh::new`] と同じく、境界上の座標は北東側の地域メッシュに含まれます。
+    pub boundary_epsilon: f64,
+}
+
+/// [`JPMesh::at_level`] の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AtLevelResult {
+    /// 指定された種類が地域メッシュと同じ種類である
+    Same,
+    /// 地域メッシュを含む、指定された種類の地域メッシュ
+    Parent(JPMesh),
+    /// 地域メッシュに含まれる、指定された種類の地域メッシュ
+    Children(Vec<JPMesh>),
+    /// 指定された種類が地域メッシュを含むことも、地域メッシュに含まれることもない (例: 5倍地域メッシュと2倍地域メッシュ)
+    Unrelated,
+}
+
+/// 座標を8分の1地域メッシュまで分解した結果
+///
+/// 座標を含む8分の1地域メッシュのメッシュコードの各桁を保持し、すべての種類の地域メッシュをその桁から求めます。
+/// 1つの座標について複数の種類の地域メッシュが必要な場合に、種類ごとに [`JPMesh::new`] を呼ぶ代わりに使用します。
+///
+/// ```
+/// use rust_jp_mesh::{Coordinates, JPMeshType, MeshDecomposition};
+///
+/// let decomposition = MeshDecomposition::new(Coordinates::new(139.767125, 35.681236));
+/// assert_eq!(decomposition.code(JPMeshType::Mesh1km).to_number(), 53394611);
+/// assert_eq!(decomposition.code(JPMeshType::Mesh10km).to_number(), 533946);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshDecomposition {
+    coords: Coordinates,
+    /// 8分の1地域メッシュのメッシュコードの各桁
+    digits: [u8; MAX_CODE_LENGTH],
+    /// 座標が地域メッシュを定義できる範囲にあるかどうか
+    in_domain: bool,
+}
+
 /// 地域メッシュを表現します。
 ///
 /// # サンプル
@@ -14,7 +159,7 @@ use crate::{
 /// assert_eq!(mesh.to_number(), 53394611);
 /// assert!(mesh.to_bounds().includes(coords));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum JPMesh {
     To125m {
         code: CodeTo125m,
@@ -32,220 +177,2835 @@ pub enum JPMesh {
 
 impl JPMesh {
     /// 指定された座標から地域メッシュを生成します。
+    ///
+    /// 地域メッシュを定義できる範囲は、南西端 (東経100度, 北緯0度) を含み、北東端 (東経180度, 北緯66度40分) を含みません。
+    /// 南西端の地域メッシュのコードはすべての桁が0 (分割番号の桁は1、2倍地域メッシュの末尾は5) となり、
+    /// 北東端に接する第1次地域区画は `9979` です。範囲外の座標に対する結果は意味を持たないため、
+    /// 範囲を確認する場合は [`JPMesh::try_new`] を使用してください。
     pub fn new(coords: Coordinates, mesh_type: JPMeshType) -> Self {
-        match mesh_type {
-            JPMeshType::Mesh1km
-            | JPMeshType::Mesh500m
-            | JPMeshType::Mesh250m
-            | JPMeshType::Mesh125m => {
-                let code = CodeTo125m::from_coordinates(coords, mesh_type);
-                JPMesh::To125m { code, mesh_type }
-            }
-            JPMeshType::Mesh2km => {
-                let code = CodeTo2km::from_coordinates(coords, mesh_type);
-                JPMesh::To2km { code, mesh_type }
-            }
-            JPMeshType::Mesh80km | JPMeshType::Mesh10km | JPMeshType::Mesh5km => {
-                let code = CodeTo5km::from_coordinates(coords, mesh_type);
-                JPMesh::To5km { code, mesh_type }
+        JPMesh::try_new(coords, mesh_type).unwrap_or_else(|_| {
+            // 範囲外の座標は、従来どおり各桁への分解によって求める
+            build_mesh!(mesh_type, |Repr| Repr::from_coordinates(coords, mesh_type))
+        })
+    }
+
+    /// 指定された座標から地域メッシュを生成します。
+    ///
+    /// 座標が地域メッシュを定義できる範囲 (経度100度〜180度、緯度0度〜66度40分) の外にある場合は
+    /// [`JPMeshError::OutOfDomain`] を返します。経度・緯度をラジアンで指定した場合もこのエラーとなります。
+    pub fn try_new(coords: Coordinates, mesh_type: JPMeshType) -> Result<Self, JPMeshError> {
+        let (col, row) = mesh_type
+            .grid_index_of(coords)
+            .ok_or(JPMeshError::OutOfDomain)?;
+        JPMesh::from_grid_index(col, row, mesh_type).ok_or(JPMeshError::OutOfDomain)
+    }
+
+    /// 指定された座標と設定から地域メッシュを生成します。
+    ///
+    /// 地域メッシュの範囲は南西端を含み北東端を含まない半開区間ですが、
+    /// 西端または南端から `opts.boundary_epsilon` 未満の距離にある座標は、それぞれ西側・南側の地域メッシュとします。
+    /// 固定の桁数に丸めた座標が境界付近にある場合でも、常に同じ地域メッシュに割り当てるために使用します。
+    /// メッシュコードで表現できる範囲の端では、この調整は行いません。
+    pub fn new_with_options(coords: Coordinates, mesh_type: JPMeshType, opts: MeshOptions) -> Self {
+        let mesh = JPMesh::new(coords, mesh_type);
+        let min = mesh.to_bounds().min();
+
+        let dcol = if coords.lng - min.lng < opts.boundary_epsilon {
+            -1
+        } else {
+            0
+        };
+        let drow = if coords.lat - min.lat < opts.boundary_epsilon {
+            -1
+        } else {
+            0
+        };
+        if dcol == 0 && drow == 0 {
+            return mesh;
+        }
+
+        mesh.shift(dcol, drow)
+            .or_else(|| mesh.shift(dcol, 0))
+            .or_else(|| mesh.shift(0, drow))
+            .unwrap_or(mesh)
+    }
+
+    /// 地域メッシュの中心の座標から地域メッシュを生成します。
+    ///
+    /// `center` が地域メッシュの中心であることを前提とし、[`JPMesh::new`] が行う境界付近の補正を省略します。
+    /// 中心は境界から半区画離れているため、座標の分解で丸めの誤差が問題になることはありません。
+    /// 任意の座標から生成する場合は [`JPMesh::new`] を、格子上の位置から生成する場合は
+    /// 座標を経由しない [`JPMesh::from_grid_index`] を使用してください。
+    pub fn from_center(center: Coordinates, mesh_type: JPMeshType) -> Self {
+        build_mesh!(mesh_type, |Repr| Repr::from_coordinates(center, mesh_type))
+    }
+
+    /// WKT の2次元の点 (例: `POINT(139.76 35.68)`) から地域メッシュを生成します。
+    ///
+    /// 点の解釈は [`Coordinates::from_wkt_point`] と同じです。
+    pub fn from_wkt_point(s: &str, mesh_type: JPMeshType) -> Result<Self, JPMeshError> {
+        Ok(JPMesh::new(Coordinates::from_wkt_point(s)?, mesh_type))
+    }
+
+    /// 統計データのファイル名 (例: `tblT001082H5339.txt`) に含まれるメッシュコードから地域メッシュを生成します。
+    ///
+    /// ディレクトリと拡張子を除いたファイル名の、末尾にある連続した数字をメッシュコードとして扱い、
+    /// 種類は文字列からの変換 ([`str::parse`]) と同じく桁数から判定します。
+    /// e-Stat の地域メッシュ統計のファイル名では、この数字はファイルが収録する第1次地域区画のメッシュコードです。
+    /// ファイル名が数字で終わらない場合や、メッシュコードとして解釈できない場合は `None` を返します。
+    pub fn from_census_filename(name: &str) -> Option<JPMesh> {
+        let file_name = name.rsplit(['/', '\\']).next()?;
+        let stem = file_name
+            .split_once('.')
+            .map_or(file_name, |(stem, _)| stem);
+
+        let digits_start = stem
+            .rfind(|c: char| !c.is_ascii_digit())
+            .map_or(0, |i| i + 1);
+        stem[digits_start..].parse().ok()
+    }
+
+    /// 指定された座標を含む、すべての種類の地域メッシュを大きいものから順に取得します。
+    ///
+    /// 座標の分解は8分の1地域メッシュについて1度だけ行い、各種類の地域メッシュはそのメッシュコードの桁から求めます。
+    /// ([`MeshDecomposition`] を参照)
+    pub fn all_levels(coords: Coordinates) -> Vec<(JPMeshType, JPMesh)> {
+        let decomposition = MeshDecomposition::new(coords);
+        JPMeshType::all()
+            .into_iter()
+            .map(|mesh_type| (mesh_type, decomposition.code(mesh_type)))
+            .collect()
+    }
+
+    /// 座標の列を地域メッシュごとにまとめ、各地域メッシュに含まれる座標の番号 (`coords` での位置) の列を取得します。
+    ///
+    /// 各座標の地域メッシュは [`JPMesh::try_new`] と同じく格子上の位置から求め、番号は昇順に並びます。
+    /// 地域メッシュを定義できる範囲の外にある座標の番号は、どの地域メッシュにも含まれません。
+    pub fn bin_indices(
+        coords: &[Coordinates],
+        mesh_type: JPMeshType,
+    ) -> HashMap<JPMesh, Vec<usize>> {
+        let mut bins: HashMap<JPMesh, Vec<usize>> = HashMap::new();
+        for (i, coords) in coords.iter().enumerate() {
+            if let Ok(mesh) = JPMesh::try_new(*coords, mesh_type) {
+                bins.entry(mesh).or_default().push(i);
             }
         }
+        bins
+    }
+
+    /// 指定された座標に中心が最も近い地域メッシュを取得します。
+    ///
+    /// 座標を含む地域メッシュとその周囲8つの地域メッシュについて、中心までの距離 (経度方向を cos(緯度) で補正した平面距離) を比較します。
+    /// 格子は規則的であるため通常は座標を含む地域メッシュとなりますが、座標がメッシュの境界上にあり距離が等しい場合は、
+    /// メッシュコードの小さい方を選びます。
+    pub fn nearest(coords: Coordinates, mesh_type: JPMeshType) -> JPMesh {
+        let containing = JPMesh::new(coords, mesh_type);
+
+        let distance = |mesh: &JPMesh| mesh.center_distance_squared(coords);
+
+        // 浮動小数点の誤差を考慮し、メッシュの大きさに対して十分小さい差は等しいものとみなす
+        let tolerance = (mesh_type.lat_interval() * mesh_type.lat_interval()) * 1e-9;
+
+        (-1..=1)
+            .flat_map(|drow| (-1..=1).map(move |dcol| (dcol, drow)))
+            .filter_map(|(dcol, drow)| containing.shift(dcol, drow))
+            .map(|mesh| (distance(&mesh), mesh))
+            .fold((distance(&containing), containing), |best, candidate| {
+                let closer = candidate.0 < best.0 - tolerance;
+                let tie = (candidate.0 - best.0).abs() <= tolerance;
+                if closer || (tie && candidate.1.to_number() < best.1.to_number()) {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .1
+    }
+
+    /// 指定された座標に最も近い、日本の第1次地域区画 ([`JPMesh::is_defined`]) に属する地域メッシュを取得します。
+    ///
+    /// 座標を含む地域メッシュから始めて、格子上のチェビシェフ距離が 1, 2, ... となる環を `max_rings` まで順に調べ、
+    /// 最初に見つかった環の中で中心が座標に最も近いもの (距離が等しい場合はメッシュコードの小さい方) を返します。
+    /// 見つからない場合は `None` を返します。
+    pub fn nearest_defined(
+        coords: Coordinates,
+        mesh_type: JPMeshType,
+        max_rings: u32,
+    ) -> Option<JPMesh> {
+        let containing = JPMesh::new(coords, mesh_type);
+
+        (0..=max_rings as i64).find_map(|k| {
+            (-k..=k)
+                .flat_map(|drow| (-k..=k).map(move |dcol| (dcol, drow)))
+                .filter(|(dcol, drow)| dcol.abs().max(drow.abs()) == k)
+                .filter_map(|(dcol, drow)| containing.shift(dcol, drow))
+                .filter(|mesh| mesh.is_defined())
+                .map(|mesh| (mesh.center_distance_squared(coords), mesh))
+                .min_by(|a, b| {
+                    a.0.total_cmp(&b.0)
+                        .then_with(|| a.1.to_number().cmp(&b.1.to_number()))
+                })
+                .map(|(_, mesh)| mesh)
+        })
+    }
+
+    /// 地域メッシュの中心から座標までの距離 (経度方向を cos(緯度) で補正した平面距離) の2乗を取得します。
+    fn center_distance_squared(&self, coords: Coordinates) -> f64 {
+        let lng_scale = coords.lat.to_radians().cos();
+        let center = self.to_bounds().center();
+        let dlng = (center.lng - coords.lng) * lng_scale;
+        let dlat = center.lat - coords.lat;
+        dlng * dlng + dlat * dlat
     }
 
     /// 指定された地域メッシュコードと種類から地域メッシュを生成します。
+    ///
+    /// メッシュコードを検証せず、panic もしません。
+    /// 桁数が種類と一致しない場合は先頭の桁を残すか末尾を0で補い、取りえない値の桁は取りうる範囲に丸めて扱います。
+    /// 不正なメッシュコードをエラーとして扱う場合は [`JPMesh::try_from_number`] を利用してください。
     pub fn from_number(mesh: u64, mesh_type: JPMeshType) -> Self {
-        match mesh_type {
-            JPMeshType::Mesh1km
-            | JPMeshType::Mesh500m
-            | JPMeshType::Mesh250m
-            | JPMeshType::Mesh125m => {
-                let code = CodeTo125m::from_number(mesh);
-                JPMesh::To125m { code, mesh_type }
-            }
-            JPMeshType::Mesh2km => {
-                let code = CodeTo2km::from_number(mesh);
-                JPMesh::To2km { code, mesh_type }
-            }
-            JPMeshType::Mesh80km | JPMeshType::Mesh10km | JPMeshType::Mesh5km => {
-                let code = CodeTo5km::from_number(mesh);
-                JPMesh::To5km { code, mesh_type }
-            }
+        let digits = clamp_code_digits(mesh, mesh_type);
+        build_mesh!(mesh_type, |Repr| Repr::new(&digits))
+    }
+
+    /// 指定された地域メッシュコードと種類から地域メッシュを生成します。
+    ///
+    /// メッシュコードの桁数が種類の桁数と一致しない場合は [`JPMeshError::InvalidCodeLength`] を、
+    /// 分割番号などの桁に取りえない値が含まれる場合は [`JPMeshError::InvalidDigit`] を返します。
+    /// 数値では先頭の0を表せないため、先頭の桁が0となるメッシュコード (緯度 6度40分 未満) は
+    /// [`JPMesh::from_id`] や文字列からの変換を利用してください。
+    pub fn try_from_number(mesh: u64, mesh_type: JPMeshType) -> Result<Self, JPMeshError> {
+        let length = decimal_length(mesh);
+        if length != mesh_type.code_length() {
+            return Err(JPMeshError::InvalidCodeLength(length));
+        }
+        check_code_digits(&decimal_digits(mesh), mesh_type)?;
+        Ok(build_mesh!(mesh_type, |Repr| {
+            <Repr as MeshCodeRepr>::from_number(mesh)?
+        }))
+    }
+
+    /// メッシュコードの数値を検査し、その種類を取得します。
+    ///
+    /// 種類は桁数から推定します。([`FromStr`] の実装と同じ規則です)
+    /// 正しくない場合は、その理由を表すエラーを返します。
+    ///
+    /// - 桁数がどの種類とも一致しない: [`JPMeshError::InvalidCodeLength`]
+    /// - 第1次地域区画の経度の桁、第2次地域区画や以降の分割番号が取りえない値: [`JPMeshError::InvalidDigit`]
+    /// - 第1次地域区画が日本の範囲に含まれない ([`JPMesh::is_defined`]): [`JPMeshError::UndefinedPrimaryMesh`]
+    pub fn diagnose_number(n: u64) -> Result<JPMeshType, JPMeshError> {
+        let digits = decimal_digits(n);
+
+        let mesh_type = mesh_type_of_digits(&digits)?;
+        check_code_digits(&digits, mesh_type)?;
+
+        let mesh = build_mesh!(mesh_type, |Repr| Repr::new(&digits).validate()?);
+        if !mesh.is_defined() {
+            return Err(JPMeshError::UndefinedPrimaryMesh(
+                n / 10u64.pow(digits.len() as u32 - 4),
+            ));
+        }
+        Ok(mesh_type)
+    }
+
+    /// 地域メッシュを、種類とメッシュコードを詰めた識別子に変換します。
+    pub fn to_id(&self) -> MeshId {
+        MeshId::new(self.mesh_type().tag(), self.to_number())
+    }
+
+    /// 識別子から地域メッシュを生成します。
+    ///
+    /// 識別子のメッシュコードが、その種類の桁数に収まらない場合や、桁に取りえない値が含まれる場合は `None` を返します。
+    pub fn from_id(id: MeshId) -> Option<JPMesh> {
+        let mesh_type = JPMeshType::from_tag(id.tag())?;
+        JPMesh::from_code_digits(id.code(), mesh_type)
+    }
+
+    /// メッシュコードを種類の桁数にあわせて先頭を0で埋め、地域メッシュを生成します。
+    fn from_code_digits(number: u64, mesh_type: JPMeshType) -> Option<JPMesh> {
+        let code_length = mesh_type.code_length();
+        if number >= 10u64.pow(code_length as u32) {
+            return None;
+        }
+
+        let mut digits = [0u8; MAX_CODE_LENGTH];
+        let mut rest = number;
+        for digit in digits[..code_length].iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
         }
+
+        let digits = &digits[..code_length];
+        check_code_digits(digits, mesh_type).ok()?;
+        Some(build_mesh!(mesh_type, |Repr| Repr::new(digits)
+            .validate()
+            .ok()?))
     }
 
     /// 地域メッシュの範囲を表す矩形を取得します。
+    ///
+    /// 矩形の中心 (`to_bounds().center()`) から [`JPMesh::new`] または [`JPMesh::from_center`] で生成した地域メッシュは、
+    /// 常にこの地域メッシュと一致します。
     pub fn to_bounds(&self) -> Rect {
-        match self {
-            Self::To125m { code, mesh_type } => code.to_bounds(*mesh_type),
-            Self::To2km { code, mesh_type } => code.to_bounds(*mesh_type),
-            Self::To5km { code, mesh_type } => code.to_bounds(*mesh_type),
+        with_code!(*self, |code, mesh_type| code.to_bounds(mesh_type))
+    }
+
+    /// 指定された座標が地域メッシュの範囲に含まれるかどうかを判定します。
+    ///
+    /// 範囲の矩形を生成せず、座標が属する格子上の位置を比較して判定します。
+    /// 判定結果は `self.to_bounds().includes(coords)` と一致し、南端・西端の辺上の座標は含み、北端・東端の辺上の座標は含みません。
+    /// 境界上かどうかを区別する場合は [`JPMesh::classify`] を使用してください。
+    pub fn is_inside(&self, coords: Coordinates) -> bool {
+        self.mesh_type().grid_index_of(coords) == Some(self.grid_index())
+    }
+
+    /// 指定された座標が、地域メッシュの範囲の内部、境界上、外のいずれにあるかを判定します。
+    ///
+    /// [`JPMesh::is_inside`] の半開区間の規則とは異なり、四辺のいずれかの上にある座標はすべて [`CellMembership::OnBoundary`] とします。
+    /// 隣接する地域メッシュが共有する座標は、どちらの地域メッシュでも [`CellMembership::OnBoundary`] となります。
+    /// 経度または緯度が NaN の座標は [`CellMembership::Outside`] とします。
+    pub fn classify(&self, coords: Coordinates) -> CellMembership {
+        let bounds = self.to_bounds();
+        let (min, max) = (bounds.min(), bounds.max());
+
+        let closed =
+            (min.lng..=max.lng).contains(&coords.lng) && (min.lat..=max.lat).contains(&coords.lat);
+        if !closed {
+            CellMembership::Outside
+        } else if coords.lng == min.lng
+            || coords.lng == max.lng
+            || coords.lat == min.lat
+            || coords.lat == max.lat
+        {
+            CellMembership::OnBoundary
+        } else {
+            CellMembership::Inside
+        }
+    }
+
+    /// 地域メッシュの範囲のうち、頂点列 `ring` で表される多角形の内部にある割合 (0〜1) を推定します。
+    ///
+    /// 範囲を経度・緯度方向にそれぞれ `samples` 等分した各区画の中心 ([`Rect::sample_grid`]) のうち、
+    /// 多角形の内部 (偶奇規則) にあるものの割合を返します。`samples` を大きくするほど精度が上がります。
+    /// `samples` が0の場合は0を返します。
+    pub fn polygon_coverage(&self, ring: &[Coordinates], samples: u32) -> f64 {
+        let points = self.to_bounds().sample_grid(samples, samples);
+        if points.is_empty() {
+            return 0.0;
         }
+
+        let inside = points
+            .iter()
+            .filter(|coords| ring_contains(ring, **coords))
+            .count();
+        inside as f64 / points.len() as f64
+    }
+
+    /// 地域メッシュの範囲と指定された矩形の、各座標の差がすべて `epsilon` 以下かどうかを判定します。
+    ///
+    /// 他の実装による計算結果との比較など、浮動小数点の誤差を許容して範囲を比較するために使用します。
+    pub fn bounds_approx_eq(&self, other: &Rect, epsilon: f64) -> bool {
+        let (a0, a1, a2, a3) = self.to_bounds().to_tuple();
+        let (b0, b1, b2, b3) = other.to_tuple();
+        [(a0, b0), (a1, b1), (a2, b2), (a3, b3)]
+            .into_iter()
+            .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+
+    /// 地域メッシュの範囲を表す矩形を、各座標を指定された小数点以下の桁数に丸めて取得します。
+    ///
+    /// [`JPMesh::to_bounds`] の結果に含まれる浮動小数点の誤差 (例: 139.76250000003) を、
+    /// 四捨五入によって取り除きます。メッシュの格子への吸着は行いません。
+    pub fn to_bounds_rounded(&self, decimals: u32) -> Rect {
+        let bounds = self.to_bounds();
+        Rect::new(
+            bounds.min().rounded(decimals),
+            bounds.max().rounded(decimals),
+        )
+    }
+
+    /// 地域メッシュの範囲を、各辺で地域メッシュの大きさの `fraction` 倍だけ外側に広げた矩形を取得します。
+    ///
+    /// 経度方向には `fraction * lng_interval`、緯度方向には `fraction * lat_interval` ずつ広げます。
+    /// 座標の誤差を許容して地域メッシュとの位置関係を判定する用途を想定しています。
+    /// 矩形の向きが逆にならないよう、負の値や NaN は0として扱います。
+    pub fn bounds_expanded_cells(&self, fraction: f64) -> Rect {
+        let fraction = fraction.max(0.0);
+        let mesh_type = self.mesh_type();
+        let lng = fraction * mesh_type.lng_interval();
+        let lat = fraction * mesh_type.lat_interval();
+
+        let bounds = self.to_bounds();
+        let (min, max) = (bounds.min(), bounds.max());
+        Rect::new(
+            Coordinates::new(min.lng - lng, min.lat - lat),
+            Coordinates::new(max.lng + lng, max.lat + lat),
+        )
+    }
+
+    /// 地域メッシュの範囲を `[最小経度, 最小緯度, 最大経度, 最大緯度]` の配列として取得します。
+    pub fn bounds_array(&self) -> [f64; 4] {
+        let bounds = self.to_bounds();
+        let [min_lng, min_lat] = bounds.min().as_array();
+        let [max_lng, max_lat] = bounds.max().as_array();
+        [min_lng, min_lat, max_lng, max_lat]
     }
 
     /// 地域メッシュコードを取得します。
     pub fn to_number(self) -> u64 {
-        match self {
-            Self::To125m { code, mesh_type } => code.to_number(mesh_type.code_length()),
-            Self::To2km { code, mesh_type } => code.to_number(mesh_type.code_length()),
-            Self::To5km { code, mesh_type } => code.to_number(mesh_type.code_length()),
-        }
+        with_code!(self, |code, mesh_type| MeshCodeRepr::to_number(
+            code, mesh_type
+        ))
+    }
+
+    /// 地域メッシュの範囲を、GeoJSON の Polygon のジオメトリ (`{"type": "Polygon", "coordinates": [...]}`) として取得します。
+    ///
+    /// Feature や FeatureCollection には含めず、ジオメトリのみを返します。外周は南西端から始まる反時計回りの閉じた頂点列です。
+    pub fn to_geojson_geometry(&self) -> Value {
+        geojson::rect_to_geometry(self.to_bounds())
+    }
+
+    /// 地域メッシュを、指定されたプロパティを持つ GeoJSON の Feature に変換します。
+    ///
+    /// プロパティには、メッシュコードが `mesh_code` として追加されます。
+    /// プロパティが JSON のオブジェクトとしてシリアライズできない場合は [`JPMeshError::InvalidProperties`] を返します。
+    pub fn to_geojson_feature_with<T: Serialize>(&self, props: &T) -> Result<Value, JPMeshError> {
+        let properties = match serde_json::to_value(props) {
+            Ok(Value::Object(properties)) => properties,
+            Ok(value) => {
+                return Err(JPMeshError::InvalidProperties(format!(
+                    "オブジェクトではありません: {}",
+                    value
+                )));
+            }
+            Err(err) => return Err(JPMeshError::InvalidProperties(err.to_string())),
+        };
+        Ok(geojson::mesh_to_feature_with(
+            self,
+            properties,
+            &GeoJsonOptions::default(),
+        ))
+    }
+
+    /// 地域メッシュの範囲を、リトルエンディアンの WKB の Polygon として取得します。
+    ///
+    /// 外周は南西端から始まる反時計回りの閉じた5点の頂点列で、座標は経度, 緯度の順です。
+    /// `srid` を指定した場合は、SRID を含む EWKB (PostGIS の拡張形式) として出力します。
+    pub fn to_wkb(&self, srid: Option<u32>) -> Vec<u8> {
+        wkb::rect_to_wkb(self.to_bounds(), srid)
+    }
+
+    /// 地域メッシュの範囲を、SRID に [`JPMESH_SRID`] を含む EWKB の Polygon として取得します。([`JPMesh::to_wkb`] を参照)
+    pub fn to_ewkb(&self) -> Vec<u8> {
+        self.to_wkb(Some(JPMESH_SRID))
+    }
+
+    /// 座標の地理座標系として想定する座標参照系 (`EPSG:4326`) を取得します。([`JPMESH_SRID`] を参照)
+    pub fn assumed_crs() -> &'static str {
+        const { assert!(JPMESH_SRID == 4326) };
+        "EPSG:4326"
+    }
+
+    /// 地域メッシュと重なる、ズームレベル `zoom` の Web メルカトル (Google/OSM 形式) のタイルの位置 (x, y) を取得します。
+    ///
+    /// 北の行から順に、各行を西から東へ並べます。地域メッシュの北端・東端にちょうど接するタイルは含みません。
+    /// Web メルカトル図法で表現できない緯度 85.0511度 を超える範囲はその緯度に切り詰めますが、
+    /// メッシュコードで表現できる範囲 (緯度66度40分 未満) では影響しません。
+    /// ズームレベルが [`MAX_WEB_TILE_ZOOM`](crate::MAX_WEB_TILE_ZOOM) を超える場合は空の列を返します。
+    pub fn covering_web_tiles(&self, zoom: u8) -> Vec<(u32, u32)> {
+        web_tile::covering_tiles(&self.to_bounds(), zoom)
+    }
+
+    /// ズームレベル `zoom` の Web メルカトルのタイル (x, y) と重なる、指定された種類の地域メッシュを取得します。
+    ///
+    /// タイルの範囲を経度・緯度の矩形に変換し、[`JPMesh::from_on_bounds`] で求めます。
+    /// ズームレベルが [`MAX_WEB_TILE_ZOOM`](crate::MAX_WEB_TILE_ZOOM) を超える場合や、タイルの位置がズームレベルの範囲外 (`2^zoom` 以上) の場合は空の列を返します。
+    pub fn in_web_tile(x: u32, y: u32, zoom: u8, mesh_type: JPMeshType) -> Vec<JPMesh> {
+        web_tile::tile_bounds(x, y, zoom)
+            .map_or_else(Vec::new, |bounds| JPMesh::from_on_bounds(bounds, mesh_type))
     }
 
     /// 地域メッシュの種類を取得します。
     pub fn mesh_type(&self) -> JPMeshType {
-        match self {
-            Self::To125m { mesh_type, .. } => *mesh_type,
-            Self::To2km { mesh_type, .. } => *mesh_type,
-            Self::To5km { mesh_type, .. } => *mesh_type,
-        }
+        with_code!(*self, |_code, mesh_type| mesh_type)
     }
 
-    /// 指定された矩形範囲に含まれる地域メッシュを取得します。
-    pub fn from_on_bounds(bounds: Rect, mesh_type: JPMeshType) -> Vec<Self> {
-        let mut mesh_bins = vec![];
-        let min = bounds.min();
-        let max = bounds.max();
-        let lat_len = ((max.lat - min.lat) / mesh_type.lat_interval()).ceil() as u64;
-        let lng_len = ((max.lng - min.lng) / mesh_type.lng_interval()).ceil() as u64;
-
-        let start = JPMesh::new(min, mesh_type).to_bounds().center();
-
-        for i in 0..=lat_len {
-            for j in 0..=lng_len {
-                let coords = Coordinates::new(
-                    start.lng + j as f64 * mesh_type.lng_interval(),
-                    start.lat + i as f64 * mesh_type.lat_interval(),
-                );
-                mesh_bins.push(JPMesh::new(coords, mesh_type));
-            }
+    /// 総務省統計局による地域メッシュの次数 (1〜6) を取得します。([`JPMeshType::standard_level_number`] を参照)
+    ///
+    /// 5倍地域メッシュ・2倍地域メッシュには次数が定められていないため `None` を返します。
+    pub fn level_number(&self) -> Option<u8> {
+        self.mesh_type().standard_level_number()
+    }
+
+    /// 地域メッシュの種類の日本語の名称 (例: `基準地域メッシュ`) を取得します。
+    pub fn level_name_ja(&self) -> &'static str {
+        self.mesh_type().japanese_name()
+    }
+
+    /// 格子上の位置 (経度方向の列番号, 緯度方向の行番号) を取得します。
+    ///
+    /// 格子は経度100度・緯度0度を原点とし、メッシュの種類ごとに南西から数えた位置を表します。
+    pub fn grid_index(&self) -> (u64, u64) {
+        with_code!(*self, |code, mesh_type| code.to_grid_index(mesh_type))
+    }
+
+    /// 格子上の位置 (経度方向の列番号, 緯度方向の行番号) から地域メッシュを生成します。
+    ///
+    /// メッシュコードで表現できる範囲の外にある場合は `None` を返します。
+    pub fn from_grid_index(col: u64, row: u64, mesh_type: JPMeshType) -> Option<Self> {
+        let (cols, rows) = mesh_type.grid_size();
+        if col >= cols || row >= rows {
+            return None;
         }
 
-        mesh_bins
+        let mesh = build_mesh!(mesh_type, |Repr| Repr::from_grid_index(col, row, mesh_type));
+
+        Some(mesh)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const EPSILON: f64 = 1e-6;
+    /// 地域メッシュに含まれる、指定された種類の地域メッシュを順に返すイテレータを取得します。
+    ///
+    /// 南の行から順に、各行を西から東へ走査します。
+    /// 指定された種類がこの地域メッシュより細かくない場合や、この地域メッシュに隙間なく含まれない場合は何も返しません。
+    pub fn iter_children(&self, target: JPMeshType) -> impl Iterator<Item = JPMesh> + use<> {
+        let (lng_factor, lat_factor) = self
+            .mesh_type()
+            .subdivision_factor(target)
+            .unwrap_or((0, 0));
+        let (col, row) = self.grid_index();
+        let (start_col, start_row) = (col * lng_factor, row * lat_factor);
 
-    #[macro_export]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {
-            assert!(
-                ($a - $b).abs() < EPSILON,
-                "assertion failed: `(left ≈ right)`\n  left: `{}`\n right: `{}`\n",
-                $a,
-                $b
-            );
-        };
+        (0..lat_factor).flat_map(move |i| {
+            (0..lng_factor)
+                .filter_map(move |j| JPMesh::from_grid_index(start_col + j, start_row + i, target))
+        })
     }
 
-    #[macro_export]
-    macro_rules! assert_mesh_size_correct {
-        ($bounds:expr, $lng_interval_seconds:expr, $lat_interval_seconds:expr) => {
-            let min_coord = $bounds.min();
-            let max_coord = $bounds.max();
-            assert_approx_eq!(
-                max_coord.lng - min_coord.lng,
-                $lng_interval_seconds / 3600.0
-            );
-            assert_approx_eq!(
-                max_coord.lat - min_coord.lat,
-                $lat_interval_seconds / 3600.0
-            );
-        };
+    /// 地域メッシュに含まれる、指定された種類の地域メッシュを取得します。
+    ///
+    /// 並び順は [`JPMesh::iter_children`] と同じです。
+    pub fn children(&self, target: JPMeshType) -> Vec<JPMesh> {
+        self.iter_children(target).collect()
     }
 
-    // small offset for checking coordinate inside the mesh
-    const INNER_OFFSET: f64 = 0.000003;
+    /// 東に隣接する地域メッシュを、近いものから順に最大 `n` 個返すイテレータを取得します。
+    ///
+    /// メッシュコードで表現できる範囲の外に出た時点で終了します。
+    pub fn walk_east(&self, n: u32) -> impl Iterator<Item = JPMesh> + use<> {
+        self.walk(1, 0, n)
+    }
 
-    #[derive(Debug)]
-    struct TestCase {
-        mesh_number: u64,
-        mesh_type: JPMeshType,
-        left_bottom: Coordinates,
+    /// 西に隣接する地域メッシュを、近いものから順に最大 `n` 個返すイテレータを取得します。
+    ///
+    /// メッシュコードで表現できる範囲の外に出た時点で終了します。
+    pub fn walk_west(&self, n: u32) -> impl Iterator<Item = JPMesh> + use<> {
+        self.walk(-1, 0, n)
     }
 
-    impl TestCase {
-        fn inner_coord(&self) -> Coordinates {
-            Coordinates::new(
-                self.left_bottom.lng + INNER_OFFSET,
-                self.left_bottom.lat + INNER_OFFSET,
-            )
-        }
+    /// 北に隣接する地域メッシュを、近いものから順に最大 `n` 個返すイテレータを取得します。
+    ///
+    /// メッシュコードで表現できる範囲の外に出た時点で終了します。
+    pub fn walk_north(&self, n: u32) -> impl Iterator<Item = JPMesh> + use<> {
+        self.walk(0, 1, n)
     }
 
-    fn get_test_cases() -> Vec<TestCase> {
-        return vec![
-            TestCase {
-                mesh_number: 64414277,
-                mesh_type: JPMeshType::Mesh1km,
-                left_bottom: Coordinates::new(141.3375, 43.058333),
-            },
-            TestCase {
-                mesh_number: 61401589,
-                mesh_type: JPMeshType::Mesh1km,
-                left_bottom: Coordinates::new(140.7375, 40.816667),
-            },
-            TestCase {
-                mesh_number: 59414142,
-                mesh_type: JPMeshType::Mesh1km,
-                left_bottom: Coordinates::new(141.15, 39.7),
-            },
-            TestCase {
-                mesh_number: 57403629,
-                mesh_type: JPMeshType::Mesh1km,
-                left_bottom: Coordinates::new(140.8625, 38.266667),
-            },
-        ];
+    /// 南に隣接する地域メッシュを、近いものから順に最大 `n` 個返すイテレータを取得します。
+    ///
+    /// メッシュコードで表現できる範囲の外に出た時点で終了します。
+    pub fn walk_south(&self, n: u32) -> impl Iterator<Item = JPMesh> + use<> {
+        self.walk(0, -1, n)
     }
 
-    #[test]
-    fn test_mesh_generation() {
-        for test_case in get_test_cases() {
-            let inner_coord = test_case.inner_coord();
-            let mesh = JPMesh::new(inner_coord, test_case.mesh_type);
+    /// 格子上で、経度方向・緯度方向ともに `k` 区画以内にある地域メッシュ (この地域メッシュ自身を含む) を取得します。
+    ///
+    /// 南の行から順に、各行の西から東へ向かって並びます。
+    /// メッシュコードで表現できる範囲の外にある地域メッシュは含みません。
+    pub fn disk(&self, k: u32) -> Vec<JPMesh> {
+        let k = k as i64;
+        (-k..=k)
+            .flat_map(|drow| (-k..=k).map(move |dcol| (dcol, drow)))
+            .filter_map(|(dcol, drow)| self.shift(dcol, drow))
+            .collect()
+    }
 
-            let actual_number = mesh.to_number();
-            assert_eq!(actual_number, test_case.mesh_number);
-        }
+    /// 格子上で周囲8区画にある地域メッシュのメッシュコードを取得します。
+    ///
+    /// [`JPMesh::disk`] から地域メッシュ自身を除いたものと同じ順に並びます。
+    /// メッシュコードで表現できる範囲の外にある地域メッシュは含みません。
+    /// 隣接関係をメッシュコードの数値のみで保持する場合に使用します。
+    pub fn neighbor_numbers(&self) -> Vec<u64> {
+        (-1..=1)
+            .flat_map(|drow| (-1..=1).map(move |dcol| (dcol, drow)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(|(dcol, drow)| self.shift(dcol, drow))
+            .map(|mesh| mesh.to_number())
+            .collect()
     }
 
-    #[test]
-    fn test_mesh_bounds() {
-        for test_case in get_test_cases() {
-            let inner_coord = test_case.inner_coord();
-            let mesh = JPMesh::new(inner_coord, test_case.mesh_type);
+    /// 地域メッシュが、メッシュコードで表現できる範囲の端にあるかどうかを判定します。
+    ///
+    /// 周囲8区画のいずれかが範囲の外となる場合に `true` を返します。
+    /// このとき [`JPMesh::disk`] は一部の地域メッシュを含みません。
+    pub fn is_edge_of_domain(&self) -> bool {
+        let (cols, rows) = self.mesh_type().grid_size();
+        let (col, row) = self.grid_index();
+        col == 0 || row == 0 || col == cols - 1 || row == rows - 1
+    }
 
-            let bounds = mesh.to_bounds();
-            let min_coord = bounds.min();
+    /// 同じ種類の地域メッシュとの、格子上のチェビシェフ距離 (斜めの移動も1区画と数えた距離) を取得します。
+    ///
+    /// 種類が異なる場合は `None` を返します。
+    pub fn chebyshev_distance(&self, other: &JPMesh) -> Option<u64> {
+        let (dcol, drow) = self.grid_delta(other)?;
+        Some(dcol.max(drow))
+    }
 
-            // check if the bottom left coordinate is correct
-            assert_approx_eq!(min_coord.lng, test_case.left_bottom.lng);
-            assert_approx_eq!(min_coord.lat, test_case.left_bottom.lat);
+    /// 同じ種類の地域メッシュとの、格子上のマンハッタン距離 (経度方向と緯度方向の区画数の和) を取得します。
+    ///
+    /// 種類が異なる場合は `None` を返します。
+    pub fn manhattan_distance(&self, other: &JPMesh) -> Option<u64> {
+        let (dcol, drow) = self.grid_delta(other)?;
+        Some(dcol + drow)
+    }
 
-            // check if the size of the area is correct
-            assert_mesh_size_correct!(bounds, 45.0, 30.0);
+    /// 同じ種類の地域メッシュとの、格子上の位置の差の絶対値 (経度方向, 緯度方向) を取得します。
+    fn grid_delta(&self, other: &JPMesh) -> Option<(u64, u64)> {
+        if self.mesh_type() != other.mesh_type() {
+            return None;
         }
+        let (col, row) = self.grid_index();
+        let (other_col, other_row) = other.grid_index();
+        Some((col.abs_diff(other_col), row.abs_diff(other_row)))
     }
 
-    #[test]
-    fn test_mesh_from_number_to_number() {
-        for test_case in get_test_cases() {
-            let mesh = JPMesh::from_number(test_case.mesh_number, test_case.mesh_type);
-            let number = mesh.to_number();
-            assert_eq!(number, test_case.mesh_number);
-        }
+    fn walk(&self, dcol: i64, drow: i64, n: u32) -> impl Iterator<Item = JPMesh> + use<> {
+        let mesh = *self;
+        (1..=n as i64).map_while(move |k| mesh.shift(dcol * k, drow * k))
     }
 
-    #[test]
-    fn test_mesh_corner() {
-        let mesh = JPMesh::new(Coordinates::new(141.15, 39.7), JPMeshType::Mesh1km);
-        let bounds = mesh.to_bounds();
-        let min_coord = bounds.min();
+    /// 格子上で経度方向に `dcol` 区画、緯度方向に `drow` 区画 (東・北が正) だけ離れた位置にある同じ種類の地域メッシュを取得します。
+    ///
+    /// 座標を経由せず、格子上の位置 ([`JPMesh::grid_index`]) から直接求めます。
+    /// [`JPMesh::walk_east`] や [`JPMesh::disk`] などの移動・走査は、この処理に基づいています。
+    /// 移動先がメッシュコードで表現できる範囲の外となる場合は `None` を返します。
+    pub fn shift(&self, dcol: i64, drow: i64) -> Option<JPMesh> {
+        let (col, row) = self.grid_index();
+        JPMesh::from_grid_index(
+            col.checked_add_signed(dcol)?,
+            row.checked_add_signed(drow)?,
+            self.mesh_type(),
+        )
+    }
 
-        assert_approx_eq!(min_coord.lng, 141.15);
-        assert_approx_eq!(min_coord.lat, 39.7);
+    /// 直上の区画を2×2に分割したうち、この地域メッシュがどの位置にあるかを取得します。
+    ///
+    /// メッシュコード末尾の分割番号 (`(行 * 2) + (列 + 1)`) から求めます。
+    /// 2×2の分割で作られない地域メッシュ (第1次・第2次地域区画、基準地域メッシュ、2倍地域メッシュ) では `None` を返します。
+    pub fn quadrant_within_parent(&self) -> Option<Quadrant> {
+        match self.mesh_type() {
+            JPMeshType::Mesh500m
+            | JPMeshType::Mesh250m
+            | JPMeshType::Mesh125m
+            | JPMeshType::Mesh5km => match self.to_number() % 10 {
+                1 => Some(Quadrant::SouthWest),
+                2 => Some(Quadrant::SouthEast),
+                3 => Some(Quadrant::NorthWest),
+                4 => Some(Quadrant::NorthEast),
+                _ => None,
+            },
+            JPMeshType::Mesh80km
+            | JPMeshType::Mesh10km
+            | JPMeshType::Mesh1km
+            | JPMeshType::Mesh2km => None,
+        }
+    }
+
+    /// 日本の地域メッシュ統計で定義されている第1次地域区画を、メッシュコードの昇順で取得します。
+    pub fn japan_primary_meshes() -> impl Iterator<Item = JPMesh> {
+        primary_mesh::japan_primary_meshes()
+            .iter()
+            .map(|&code| JPMesh::from_number(code as u64, JPMeshType::Mesh80km))
+    }
+
+    /// 地域メッシュが、日本の地域メッシュ統計で定義されている第1次地域区画に含まれるかどうかを判定します。
+    ///
+    /// メッシュコードとして正しくても、海上などで統計の対象外となる第1次地域区画に含まれる場合は `false` を返します。
+    pub fn is_defined(&self) -> bool {
+        let primary = match self.mesh_type() {
+            JPMeshType::Mesh80km => *self,
+            _ => match self.parent(JPMeshType::Mesh80km) {
+                Some(primary) => primary,
+                None => return false,
+            },
+        };
+        primary_mesh::is_japan_primary_mesh(primary.to_number())
+    }
+
+    /// 地域メッシュの範囲に含まれる標本の、重みによる加重平均の座標を取得します。
+    ///
+    /// 人口などの細かいデータから、地域メッシュの代表点を求めるために使用します。
+    /// 標本は `(座標, 重み)` の組で、範囲に含まれるかどうかは [`JPMesh::is_inside`] で判定します。
+    /// 範囲に含まれる標本がない場合や、重みの合計が正の有限の値とならない場合は `None` を返します。
+    /// 重みがすべて等しい場合は、範囲に含まれる標本の [`centroid`](crate::centroid) と一致します。
+    pub fn weighted_center(&self, samples: &[(Coordinates, f64)]) -> Option<Coordinates> {
+        let (lng, lat, total) = samples
+            .iter()
+            .filter(|(coords, _)| self.is_inside(*coords))
+            .fold((0.0, 0.0, 0.0), |(lng, lat, total), (coords, weight)| {
+                (
+                    lng + coords.lng * weight,
+                    lat + coords.lat * weight,
+                    total + weight,
+                )
+            });
+
+        (total > 0.0 && total.is_finite()).then(|| Coordinates::new(lng / total, lat / total))
+    }
+
+    /// 地域メッシュの中心が属する都道府県のコード (JIS X 0401, 1〜47) を取得します。
+    ///
+    /// 第2次地域区画 (10km) ごとの都道府県の対応表を引き、該当が無ければ第1次地域区画の都道府県を返します。
+    /// 第1次地域区画はその区画で最も広い範囲を占める都道府県を返します。
+    /// 対応表は市区町村の代表点から生成した概算 (`tools/prefecture_table.py`) であり、
+    /// 県境をまたぐ区画では実際と異なる場合があります。
+    /// 日本の地域メッシュ統計で定義されていない第1次地域区画にある場合は `None` を返します。
+    pub fn prefecture_code(&self) -> Option<u8> {
+        if self.mesh_type() == JPMeshType::Mesh80km {
+            return prefecture::prefecture_code_of_primary(self.to_number());
+        }
+        let secondary = JPMesh::try_new(self.to_bounds().center(), JPMeshType::Mesh10km).ok()?;
+        prefecture::prefecture_code_of_secondary(secondary.to_number())
+    }
+
+    /// 基準地域メッシュ (1km) を、4つの2分の1地域メッシュ (500m) に分割します。
+    ///
+    /// メッシュコードの末尾に 1〜4 を付け加えて、南西, 南東, 北西, 北東 の順に返します。
+    /// 基準地域メッシュ以外に対しては `None` を返します。
+    pub fn into_500m(&self) -> Option<[JPMesh; 4]> {
+        if self.mesh_type() != JPMeshType::Mesh1km {
+            return None;
+        }
+        let number = self.to_number() * 10;
+        Some([1, 2, 3, 4].map(|m| {
+            JPMesh::from_code_digits(number + m, JPMeshType::Mesh500m)
+                .expect("500m code should have 9 digits")
+        }))
+    }
+
+    /// 2つの種類の間の分割数 (経度方向, 緯度方向) を取得します。
+    ///
+    /// 一方の種類のメッシュ1つが、もう一方の種類のメッシュで隙間なく分割される場合に、その分割数を返します。
+    /// 指定する順序は問いません。(例: 80km メッシュと 1km メッシュの間は `(80, 80)`)
+    /// 同じ種類の場合は `(1, 1)` を、包含関係にない場合は `None` を返します。
+    pub fn scale_factor(from: JPMeshType, to: JPMeshType) -> Option<(u32, u32)> {
+        if from == to {
+            return Some((1, 1));
+        }
+        let (lng, lat) = from
+            .subdivision_factor(to)
+            .or_else(|| to.subdivision_factor(from))?;
+        Some((lng as u32, lat as u32))
+    }
+
+    /// 地域メッシュを含む、指定された種類の地域メッシュを取得します。
+    ///
+    /// 指定された種類がこの地域メッシュより大きくない場合や、この地域メッシュを隙間なく含まない場合は `None` を返します。
+    pub fn parent(&self, level: JPMeshType) -> Option<JPMesh> {
+        let (lng_factor, lat_factor) = level.subdivision_factor(self.mesh_type())?;
+        let (col, row) = self.grid_index();
+        JPMesh::from_grid_index(col / lng_factor, row / lat_factor, level)
+    }
+
+    /// 地域メッシュを含む、指定された種類の地域メッシュのコードを、メッシュコードの桁の切り捨てによって取得します。
+    ///
+    /// 指定された種類のメッシュコードがこの地域メッシュのコードの先頭部分とならない場合は `None` を返します。
+    /// ([`JPMeshType::code_prefix_length`] を参照)
+    pub fn parent_code_number(&self, level: JPMeshType) -> Option<u64> {
+        let mesh_type = self.mesh_type();
+        let prefix_length = mesh_type.code_prefix_length(level)?;
+        Some(self.to_number() / 10u64.pow((mesh_type.code_length() - prefix_length) as u32))
+    }
+
+    /// 指定された種類の同じ地域メッシュに含まれる、この地域メッシュと同じ種類の地域メッシュを取得します。
+    ///
+    /// 結果にこの地域メッシュ自身は含みません。順序は [`JPMesh::children`] と同じです。
+    /// 指定された種類の地域メッシュに含まれない場合 ([`JPMesh::parent`] が `None` の場合) は空の列を返します。
+    pub fn siblings(&self, parent_level: JPMeshType) -> Vec<JPMesh> {
+        let Some(parent) = self.parent(parent_level) else {
+            return Vec::new();
+        };
+        parent
+            .iter_children(self.mesh_type())
+            .filter(|mesh| mesh != self)
+            .collect()
+    }
+
+    /// 2つの地域メッシュをともに含む、最も細かい標準地域メッシュを取得します。
+    ///
+    /// 第1次地域区画から8分の1地域メッシュまでの階層を細かい方からさかのぼり、
+    /// 両方の地域メッシュが同じ地域メッシュに含まれる最初の階層の地域メッシュを返します。
+    /// 第1次地域区画でも異なる場合は `None` を返します。
+    pub fn common_ancestor(&self, other: &JPMesh) -> Option<JPMesh> {
+        let ancestor = |mesh: &JPMesh, level: JPMeshType| {
+            if mesh.mesh_type() == level {
+                Some(*mesh)
+            } else {
+                mesh.parent(level)
+            }
+        };
+
+        let mut standard_levels = JPMeshType::all()
+            .into_iter()
+            .rev()
+            .filter(|level| level.is_standard());
+        standard_levels.find_map(|level| {
+            let a = ancestor(self, level)?;
+            (ancestor(other, level)? == a).then_some(a)
+        })
+    }
+
+    /// 地域メッシュを、指定された種類の地域メッシュとして表現します。
+    ///
+    /// 同じ種類であれば [`AtLevelResult::Same`]、大きい種類であれば含む地域メッシュ、
+    /// 細かい種類であれば含まれる地域メッシュを返します。
+    pub fn at_level(&self, level: JPMeshType) -> AtLevelResult {
+        if level == self.mesh_type() {
+            return AtLevelResult::Same;
+        }
+        if let Some(parent) = self.parent(level) {
+            return AtLevelResult::Parent(parent);
+        }
+
+        let children = self.children(level);
+        if children.is_empty() {
+            AtLevelResult::Unrelated
+        } else {
+            AtLevelResult::Children(children)
+        }
+    }
+
+    /// 地域メッシュの東西方向の幅を取得します。(メートル)
+    pub fn cell_width_meters(&self) -> f64 {
+        self.to_bounds().width_meters()
+    }
+
+    /// 地域メッシュの南北方向の高さを取得します。(メートル)
+    pub fn cell_height_meters(&self) -> f64 {
+        self.to_bounds().height_meters()
+    }
+
+    /// 地域メッシュの面積のうち、指定された矩形と重なる部分の割合 (0.0〜1.0) を取得します。
+    ///
+    /// 面積は経度・緯度の度を単位として計算します。小さなメッシュでは、緯度による歪みは無視できる程度です。
+    pub fn coverage_ratio(&self, rect: &Rect) -> f64 {
+        let bounds = self.to_bounds();
+        let Some(overlap) = bounds.intersection(rect) else {
+            return 0.0;
+        };
+
+        let area = |r: &Rect| (r.max().lng - r.min().lng) * (r.max().lat - r.min().lat);
+        (area(&overlap) / area(&bounds)).clamp(0.0, 1.0)
+    }
+
+    /// 指定された矩形範囲と重なる地域メッシュを取得します。
+    ///
+    /// 範囲の扱いは [`JPMesh::iter_on_bounds`] と同じです。
+    pub fn from_on_bounds(bounds: Rect, mesh_type: JPMeshType) -> Vec<Self> {
+        JPMesh::iter_on_bounds(bounds, mesh_type).collect()
+    }
+
+    /// 指定された矩形範囲と重なる地域メッシュを、南の行から順に、各行の西から東へ向かって生成するイテレータを取得します。
+    ///
+    /// 矩形は南西端を含み北東端を含まないものとして扱うため、北端・東端が地域メッシュの境界に一致する場合、
+    /// その外側の地域メッシュは含みません。幅が0の方向は、南西端の座標を含む地域メッシュのみとします。
+    /// 地域メッシュはイテレータを進めるたびに生成されるため、広い範囲でもすべてをメモリに保持しません。
+    /// メッシュコードで表現できる範囲の外にある地域メッシュは含みません。
+    /// 生成される地域メッシュの数は [`JPMeshType::cell_count_over`] で事前に確認できます。
+    pub fn iter_on_bounds(
+        bounds: Rect,
+        mesh_type: JPMeshType,
+    ) -> impl Iterator<Item = JPMesh> + use<> {
+        JPMesh::iter_on_bounds_ordered(bounds, mesh_type, TraversalOrder::RowMajor)
+    }
+
+    /// 指定された矩形範囲に含まれる地域メッシュを、指定された順序で生成するイテレータを取得します。
+    ///
+    /// 含まれる地域メッシュは [`JPMesh::iter_on_bounds`] と同じで、順序のみが異なります。([`TraversalOrder`] を参照)
+    pub fn iter_on_bounds_ordered(
+        bounds: Rect,
+        mesh_type: JPMeshType,
+        order: TraversalOrder,
+    ) -> impl Iterator<Item = JPMesh> + use<> {
+        mesh_type.grid_index_range(&bounds).into_iter().flat_map(
+            move |((col0, row0), (col1, row1))| {
+                let (cols, rows) = (col1 - col0 + 1, row1 - row0 + 1);
+                (0..cols * rows).filter_map(move |i| {
+                    let (dcol, drow) = match order {
+                        TraversalOrder::RowMajor => (i % cols, i / cols),
+                        TraversalOrder::ColumnMajor => (i / rows, i % rows),
+                        TraversalOrder::Serpentine => {
+                            let drow = i / cols;
+                            if drow.is_multiple_of(2) {
+                                (i % cols, drow)
+                            } else {
+                                (cols - 1 - i % cols, drow)
+                            }
+                        }
+                    };
+                    JPMesh::from_grid_index(col0 + dcol, row0 + drow, mesh_type)
+                })
+            },
+        )
+    }
+
+    /// 指定された矩形範囲に含まれる地域メッシュのうち、条件を満たすものだけを生成するイテレータを取得します。
+    ///
+    /// [`JPMesh::iter_on_bounds`] と同じ順序で、生成しながら条件を判定します。
+    pub fn iter_on_bounds_filtered(
+        bounds: Rect,
+        mesh_type: JPMeshType,
+        pred: impl Fn(&JPMesh) -> bool,
+    ) -> impl Iterator<Item = JPMesh> {
+        JPMesh::iter_on_bounds(bounds, mesh_type).filter(move |mesh| pred(mesh))
+    }
+
+    /// 指定された矩形範囲に含まれる地域メッシュと、その範囲のうち矩形の内側にある部分を順に返すイテレータを取得します。
+    ///
+    /// [`JPMesh::iter_on_bounds`] と同じ地域メッシュを同じ順序で返します。
+    /// 矩形に完全に含まれる地域メッシュでは地域メッシュの範囲そのものを、矩形の端にかかる地域メッシュでは切り取った範囲を返します。
+    /// 矩形の幅が0の方向では、切り取った範囲の幅も0となります。
+    pub fn iter_clipped(
+        bounds: Rect,
+        mesh_type: JPMeshType,
+    ) -> impl Iterator<Item = (JPMesh, Rect)> + use<> {
+        JPMesh::iter_on_bounds(bounds, mesh_type).map(move |mesh| {
+            let cell = mesh.to_bounds();
+            let (min, max) = (cell.min(), cell.max());
+            let clipped = Rect::new(
+                Coordinates::new(min.lng.max(bounds.min().lng), min.lat.max(bounds.min().lat)),
+                Coordinates::new(max.lng.min(bounds.max().lng), max.lat.min(bounds.max().lat)),
+            );
+            (mesh, clipped)
+        })
+    }
+}
+
+impl MeshDecomposition {
+    /// 指定された座標を分解します。
+    pub fn new(coords: Coordinates) -> Self {
+        let finest = JPMesh::try_new(coords, JPMeshType::Mesh125m);
+        let in_domain = finest.is_ok();
+        let finest = finest.unwrap_or_else(|_| JPMesh::new(coords, JPMeshType::Mesh125m));
+        let JPMesh::To125m { code, .. } = finest else {
+            unreachable!("Mesh125m is represented by CodeTo125m");
+        };
+
+        Self {
+            coords,
+            digits: code.to_array(),
+            in_domain,
+        }
+    }
+
+    /// 分解した座標
+    pub fn coords(&self) -> Coordinates {
+        self.coords
+    }
+
+    /// 座標を含む、指定された種類の地域メッシュを取得します。([`JPMesh::new`] と同じ結果)
+    ///
+    /// 8分の1地域メッシュのメッシュコードの桁から求めます。5倍地域メッシュの分割番号と2倍地域メッシュの桁は、
+    /// 基準地域メッシュの桁 (r, w) から求めます。座標が地域メッシュを定義できる範囲の外にある場合は [`JPMesh::new`] を使用します。
+    pub fn code(&self, mesh_type: JPMeshType) -> JPMesh {
+        if !self.in_domain {
+            return JPMesh::new(self.coords, mesh_type);
+        }
+
+        let digits = &self.digits;
+        let (r, w) = (digits[6], digits[7]);
+        match mesh_type {
+            JPMeshType::Mesh5km => {
+                let m = (r / 5) * 2 + (w / 5) + 1;
+                let code = [
+                    digits[0], digits[1], digits[2], digits[3], digits[4], digits[5], m,
+                ];
+                build_mesh!(mesh_type, |Repr| Repr::new(&code))
+            }
+            JPMeshType::Mesh2km => {
+                let code = [
+                    digits[0],
+                    digits[1],
+                    digits[2],
+                    digits[3],
+                    digits[4],
+                    digits[5],
+                    r / 2 * 2,
+                    w / 2 * 2,
+                    5,
+                ];
+                build_mesh!(mesh_type, |Repr| Repr::new(&code))
+            }
+            _ => build_mesh!(mesh_type, |Repr| Repr::new(
+                &digits[..mesh_type.code_length()]
+            )),
+        }
+    }
+
+    /// 8分の1地域メッシュのメッシュコードの各桁 (p1 p2 u1 u2 q v r w m n nn) を取得します。
+    ///
+    /// 先頭の8桁が基準地域メッシュ、続く3桁が2分の1・4分の1・8分の1地域メッシュの分割番号 (1〜4) です。
+    pub fn digits(&self) -> [u8; MAX_CODE_LENGTH] {
+        self.digits
+    }
+}
+
+/// メッシュコードの区切りの位置 (先頭からの桁数)
+///
+/// 第1次地域区画 (4桁), 第2次地域区画 (2桁), 第3次地域区画 (2桁), 以降は1桁ずつ区切ります。
+const SECTION_ENDS: [usize; 6] = [4, 6, 8, 9, 10, 11];
+
+/// `{}` では先頭の0を含むメッシュコード (例: `53394611`) を、
+/// `{:#}` では区画ごとにハイフンで区切ったメッシュコード (例: `5339-46-11`) を出力します。
+impl fmt::Display for JPMesh {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code_length = self.mesh_type().code_length();
+        let digits = format!("{:0width$}", self.to_number(), width = code_length);
+
+        if !f.alternate() {
+            return f.write_str(&digits);
+        }
+
+        let mut start = 0;
+        for end in SECTION_ENDS {
+            let end = end.min(code_length);
+            if start >= end {
+                break;
+            }
+            if start > 0 {
+                f.write_str("-")?;
+            }
+            f.write_str(&digits[start..end])?;
+            start = end;
+        }
+
+        Ok(())
+    }
+}
+
+/// メッシュコードの文字列から地域メッシュを生成します。
+///
+/// 地域メッシュの種類は桁数から推定します。9桁のメッシュコードは、末尾が5の場合は2倍地域メッシュ、
+/// それ以外の場合は2分の1地域メッシュとして扱います。ハイフン区切りの文字列 (例: `5339-46-11`) も受け付けますが、
+/// ハイフンは `{:#}` の出力と同じ区切りの位置 (先頭から4, 6, 8, 9, 10桁目の後) にのみ置くことができます。
+impl FromStr for JPMesh {
+    type Err = JPMeshError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || JPMeshError::InvalidFormat(s.to_string());
+
+        let mut digits = Vec::with_capacity(MAX_CODE_LENGTH);
+        let mut hyphen_allowed = false;
+        for c in s.chars() {
+            if c == '-' {
+                if !hyphen_allowed {
+                    return Err(invalid());
+                }
+                hyphen_allowed = false;
+                continue;
+            }
+            digits.push(c.to_digit(10).ok_or_else(invalid)? as u8);
+            hyphen_allowed = SECTION_ENDS.contains(&digits.len());
+        }
+        if s.ends_with('-') {
+            return Err(invalid());
+        }
+
+        let mesh_type = mesh_type_of_digits(&digits)?;
+        check_code_digits(&digits, mesh_type)?;
+
+        Ok(build_mesh!(mesh_type, |Repr| Repr::new(&digits).validate()?))
+    }
+}
+
+/// 数値の各桁を上の桁から順に取得します。(678954 -> [6, 7, 8, 9, 5, 4])
+fn decimal_digits(n: u64) -> Vec<u8> {
+    n.to_string().bytes().map(|b| b - b'0').collect()
+}
+
+/// メッシュコードの数値の各桁を、指定された種類の桁数と各桁の取りうる値 ([`JPMeshType::code_digit_ranges`]) に丸めます。
+///
+/// 桁数が多い場合は先頭の桁を残し、少ない場合は末尾を0で補います。
+fn clamp_code_digits(n: u64, mesh_type: JPMeshType) -> Vec<u8> {
+    let mut digits = decimal_digits(n);
+    digits.resize(mesh_type.code_length(), 0);
+    for (digit, range) in digits.iter_mut().zip(mesh_type.code_digit_ranges()) {
+        *digit = (*digit).clamp(*range.start(), *range.end());
+    }
+    digits
+}
+
+/// メッシュコードの各桁から、地域メッシュの種類を推定します。
+///
+/// 9桁のメッシュコードは、末尾が5の場合は2倍地域メッシュ、1〜4の場合は2分の1地域メッシュとします。
+fn mesh_type_of_digits(digits: &[u8]) -> Result<JPMeshType, JPMeshError> {
+    let mesh_type = match digits.len() {
+        4 => JPMeshType::Mesh80km,
+        6 => JPMeshType::Mesh10km,
+        7 => JPMeshType::Mesh5km,
+        8 => JPMeshType::Mesh1km,
+        9 => match digits[8] {
+            5 => JPMeshType::Mesh2km,
+            1..=4 => JPMeshType::Mesh500m,
+            digit => return Err(JPMeshError::InvalidDigit { position: 8, digit }),
+        },
+        10 => JPMeshType::Mesh250m,
+        11 => JPMeshType::Mesh125m,
+        length => return Err(JPMeshError::InvalidCodeLength(length)),
+    };
+    Ok(mesh_type)
+}
+
+/// メッシュコードの各桁が、指定された種類の地域メッシュとして取りうる値 ([`JPMeshType::code_digit_ranges`]) かどうかを確認します。
+///
+/// 2倍地域メッシュの桁が偶数であることは [`MeshCodeRepr::validate`] で確認します。
+fn check_code_digits(digits: &[u8], mesh_type: JPMeshType) -> Result<(), JPMeshError> {
+    digits
+        .iter()
+        .zip(mesh_type.code_digit_ranges())
+        .enumerate()
+        .try_for_each(|(position, (&digit, range))| {
+            if range.contains(&digit) {
+                Ok(())
+            } else {
+                Err(JPMeshError::InvalidDigit { position, digit })
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EPSILON: f64 = 1e-6;
+
+    #[macro_export]
+    macro_rules! assert_approx_eq {
+        ($a:expr, $b:expr) => {
+            assert!(
+                ($a - $b).abs() < EPSILON,
+                "assertion failed: `(left ≈ right)`\n  left: `{}`\n right: `{}`\n",
+                $a,
+                $b
+            );
+        };
+    }
+
+    #[macro_export]
+    macro_rules! assert_mesh_size_correct {
+        ($bounds:expr, $lng_interval_seconds:expr, $lat_interval_seconds:expr) => {
+            let min_coord = $bounds.min();
+            let max_coord = $bounds.max();
+            assert_approx_eq!(
+                max_coord.lng - min_coord.lng,
+                $lng_interval_seconds / 3600.0
+            );
+            assert_approx_eq!(
+                max_coord.lat - min_coord.lat,
+                $lat_interval_seconds / 3600.0
+            );
+        };
+    }
+
+    // small offset for checking coordinate inside the mesh
+    const INNER_OFFSET: f64 = 0.000003;
+
+    #[derive(Debug)]
+    struct TestCase {
+        mesh_number: u64,
+        mesh_type: JPMeshType,
+        left_bottom: Coordinates,
+    }
+
+    impl TestCase {
+        fn inner_coord(&self) -> Coordinates {
+            Coordinates::new(
+                self.left_bottom.lng + INNER_OFFSET,
+                self.left_bottom.lat + INNER_OFFSET,
+            )
+        }
+    }
+
+    fn get_test_cases() -> Vec<TestCase> {
+        vec![
+            TestCase {
+                mesh_number: 64414277,
+                mesh_type: JPMeshType::Mesh1km,
+                left_bottom: Coordinates::new(141.3375, 43.058333),
+            },
+            TestCase {
+                mesh_number: 61401589,
+                mesh_type: JPMeshType::Mesh1km,
+                left_bottom: Coordinates::new(140.7375, 40.816667),
+            },
+            TestCase {
+                mesh_number: 59414142,
+                mesh_type: JPMeshType::Mesh1km,
+                left_bottom: Coordinates::new(141.15, 39.7),
+            },
+            TestCase {
+                mesh_number: 57403629,
+                mesh_type: JPMeshType::Mesh1km,
+                left_bottom: Coordinates::new(140.8625, 38.266667),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_mesh_generation() {
+        for test_case in get_test_cases() {
+            let inner_coord = test_case.inner_coord();
+            let mesh = JPMesh::new(inner_coord, test_case.mesh_type);
+
+            let actual_number = mesh.to_number();
+            assert_eq!(actual_number, test_case.mesh_number);
+        }
+    }
+
+    #[test]
+    fn test_mesh_bounds() {
+        for test_case in get_test_cases() {
+            let inner_coord = test_case.inner_coord();
+            let mesh = JPMesh::new(inner_coord, test_case.mesh_type);
+
+            let bounds = mesh.to_bounds();
+            let min_coord = bounds.min();
+
+            // check if the bottom left coordinate is correct
+            assert_approx_eq!(min_coord.lng, test_case.left_bottom.lng);
+            assert_approx_eq!(min_coord.lat, test_case.left_bottom.lat);
+
+            // check if the size of the area is correct
+            assert_mesh_size_correct!(bounds, 45.0, 30.0);
+        }
+    }
+
+    #[test]
+    fn test_mesh_from_number_to_number() {
+        for test_case in get_test_cases() {
+            let mesh = JPMesh::from_number(test_case.mesh_number, test_case.mesh_type);
+            let number = mesh.to_number();
+            assert_eq!(number, test_case.mesh_number);
+        }
+    }
+
+    #[test]
+    fn test_mesh_corner() {
+        let mesh = JPMesh::new(Coordinates::new(141.15, 39.7), JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+        let min_coord = bounds.min();
+
+        assert_approx_eq!(min_coord.lng, 141.15);
+        assert_approx_eq!(min_coord.lat, 39.7);
+    }
+
+    #[test]
+    fn test_mesh_cell_size_meters() {
+        let mesh = JPMesh::new(Coordinates::new(139.767125, 35.0), JPMeshType::Mesh1km);
+        let width = mesh.cell_width_meters();
+        let height = mesh.cell_height_meters();
+
+        // 基準地域メッシュはおよそ1km四方
+        assert!((900.0..1200.0).contains(&width), "width: {}", width);
+        assert!((900.0..1200.0).contains(&height), "height: {}", height);
+
+        // 経度方向の長さは cos(緯度) の分だけ赤道上より短くなる
+        let lng_degrees = JPMeshType::Mesh1km.lng_interval();
+        let equator_width = lng_degrees * crate::earth::meters_per_deg_lat();
+        assert!(width < equator_width);
+        assert_approx_eq!(
+            width / equator_width,
+            mesh.to_bounds().center().lat.to_radians().cos()
+        );
+    }
+
+    #[test]
+    fn test_mesh_grid_index() {
+        let mesh_types = [
+            JPMeshType::Mesh80km,
+            JPMeshType::Mesh10km,
+            JPMeshType::Mesh5km,
+            JPMeshType::Mesh2km,
+            JPMeshType::Mesh1km,
+            JPMeshType::Mesh500m,
+            JPMeshType::Mesh250m,
+            JPMeshType::Mesh125m,
+        ];
+        let coords = Coordinates::new(139.767125, 35.681236);
+
+        for mesh_type in mesh_types {
+            let mesh = JPMesh::new(coords, mesh_type);
+            let (col, row) = mesh.grid_index();
+
+            let min = mesh.to_bounds().min();
+            assert_approx_eq!(col as f64 * mesh_type.lng_interval() + 100.0, min.lng);
+            assert_approx_eq!(row as f64 * mesh_type.lat_interval(), min.lat);
+
+            assert_eq!(JPMesh::from_grid_index(col, row, mesh_type), Some(mesh));
+        }
+    }
+
+    #[test]
+    fn test_mesh_children() {
+        let parent = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let children = parent.children(JPMeshType::Mesh500m);
+        let numbers: Vec<u64> = children.iter().map(|c| c.to_number()).collect();
+        assert_eq!(numbers, vec![533946111, 533946112, 533946113, 533946114]);
+
+        let parent = JPMesh::from_number(5339, JPMeshType::Mesh80km);
+        assert_eq!(parent.iter_children(JPMeshType::Mesh1km).count(), 6400);
+        assert_eq!(parent.iter_children(JPMeshType::Mesh2km).count(), 1600);
+
+        for child in parent.iter_children(JPMeshType::Mesh10km) {
+            let center = child.to_bounds().center();
+            assert!(parent.to_bounds().includes(center));
+            assert_eq!(JPMesh::new(center, JPMeshType::Mesh10km), child);
+        }
+
+        let first = parent.iter_children(JPMeshType::Mesh125m).next().unwrap();
+        assert_eq!(first.to_number(), 53390000111);
+    }
+
+    #[test]
+    fn test_mesh_children_not_finer() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(mesh.iter_children(JPMeshType::Mesh1km).count(), 0);
+        assert_eq!(mesh.iter_children(JPMeshType::Mesh10km).count(), 0);
+
+        let mesh = JPMesh::from_number(5339461, JPMeshType::Mesh5km);
+        assert!(mesh.children(JPMeshType::Mesh2km).is_empty());
+        assert_eq!(mesh.children(JPMeshType::Mesh1km).len(), 25);
+    }
+
+    #[test]
+    fn test_mesh_bounds_rounded() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds_rounded(6);
+
+        assert_eq!(bounds.min(), Coordinates::new(139.7625, 35.675));
+        assert_eq!(bounds.max(), Coordinates::new(139.775, 35.683333));
+    }
+
+    #[test]
+    fn test_mesh_walk() {
+        let numbers = |iter: &mut dyn Iterator<Item = JPMesh>| -> Vec<u64> {
+            iter.map(|mesh| mesh.to_number()).collect()
+        };
+
+        let mesh = JPMesh::from_number(53394618, JPMeshType::Mesh1km);
+        assert_eq!(
+            numbers(&mut mesh.walk_east(3)),
+            vec![53394619, 53394710, 53394711]
+        );
+        assert_eq!(numbers(&mut mesh.walk_west(2)), vec![53394617, 53394616]);
+        assert_eq!(numbers(&mut mesh.walk_north(2)), vec![53394628, 53394638]);
+        assert_eq!(numbers(&mut mesh.walk_south(2)), vec![53394608, 53393698]);
+
+        // 各メッシュは座標から生成したものと一致する
+        for next in mesh.walk_north(20) {
+            let center = next.to_bounds().center();
+            assert_eq!(JPMesh::new(center, JPMeshType::Mesh1km), next);
+        }
+    }
+
+    #[test]
+    fn test_mesh_walk_leaves_domain() {
+        let mesh = JPMesh::from_grid_index(0, 0, JPMeshType::Mesh80km).unwrap();
+        assert_eq!(mesh.walk_west(3).count(), 0);
+        assert_eq!(mesh.walk_south(3).count(), 0);
+
+        let mesh = JPMesh::from_number(9779, JPMeshType::Mesh80km);
+        assert_eq!(mesh.walk_north(5).count(), 2);
+        assert_eq!(mesh.walk_east(5).count(), 0);
+    }
+
+    #[test]
+    fn test_mesh_prefecture_code() {
+        let cases = [
+            (Coordinates::new(139.767125, 35.681236), Some(13)), // 東京駅
+            (Coordinates::new(141.350755, 43.068661), Some(1)),  // 札幌駅
+            (Coordinates::new(135.495951, 34.702485), Some(27)), // 大阪駅
+            (Coordinates::new(130.420622, 33.589728), Some(40)), // 博多駅
+            (Coordinates::new(127.679, 26.2125), Some(47)),      // 那覇市
+            (Coordinates::new(140.73, 41.77), Some(1)),          // 函館市
+            (Coordinates::new(129.29, 34.20), Some(42)),         // 対馬市厳原
+            (Coordinates::new(129.35, 34.60), Some(42)),         // 対馬市上県
+            (Coordinates::new(138.43, 38.08), Some(15)),         // 佐渡市両津
+            (Coordinates::new(138.28, 37.81), Some(15)),         // 佐渡市小木
+            (Coordinates::new(170.0, 35.0), None),
+        ];
+
+        for (coords, expected) in cases {
+            let mesh = JPMesh::new(coords, JPMeshType::Mesh1km);
+            assert_eq!(mesh.prefecture_code(), expected, "{:?}", coords);
+        }
+
+        // 第1次地域区画は区画内で最も広い都道府県を返す
+        assert_eq!(
+            JPMesh::from_number(5738, JPMeshType::Mesh80km).prefecture_code(),
+            Some(15)
+        );
+        assert_eq!(
+            JPMesh::from_number(4050, JPMeshType::Mesh80km).prefecture_code(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mesh_bounds_array() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+        assert_eq!(
+            mesh.bounds_array(),
+            [
+                bounds.min().lng,
+                bounds.min().lat,
+                bounds.max().lng,
+                bounds.max().lat
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mesh_display() {
+        let cases = [
+            (5339, JPMeshType::Mesh80km, "5339", "5339"),
+            (533946, JPMeshType::Mesh10km, "533946", "5339-46"),
+            (5339461, JPMeshType::Mesh5km, "5339461", "5339-46-1"),
+            (533946405, JPMeshType::Mesh2km, "533946405", "5339-46-40-5"),
+            (53394611, JPMeshType::Mesh1km, "53394611", "5339-46-11"),
+            (
+                53394611432,
+                JPMeshType::Mesh125m,
+                "53394611432",
+                "5339-46-11-4-3-2",
+            ),
+        ];
+
+        for (number, mesh_type, plain, hyphenated) in cases {
+            let mesh = JPMesh::from_number(number, mesh_type);
+            assert_eq!(format!("{}", mesh), plain);
+            assert_eq!(format!("{:#}", mesh), hyphenated);
+        }
+
+        let mesh = JPMesh::from_grid_index(0, 0, JPMeshType::Mesh1km).unwrap();
+        assert_eq!(mesh.to_string(), "00000000");
+    }
+
+    #[test]
+    fn test_mesh_all_levels() {
+        let coords = [
+            Coordinates::new(139.767125, 35.681236),
+            Coordinates::new(141.15, 39.7),
+            Coordinates::new(140.0, 40.0),
+        ];
+
+        for coords in coords {
+            let levels = JPMesh::all_levels(coords);
+            assert_eq!(levels.len(), JPMeshType::all().len());
+
+            for (mesh_type, mesh) in levels {
+                assert_eq!(mesh.mesh_type(), mesh_type);
+                assert_eq!(mesh, JPMesh::new(coords, mesh_type));
+                assert!(mesh.to_bounds().includes(coords));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mesh_nearest() {
+        // 4つの地域メッシュが接する点 (53394611 の北東の角)
+        let junction = JPMesh::from_number(53394611, JPMeshType::Mesh1km)
+            .to_bounds()
+            .max();
+        assert_eq!(
+            JPMesh::new(junction, JPMeshType::Mesh1km).to_number(),
+            53394622
+        );
+        assert_eq!(
+            JPMesh::nearest(junction, JPMeshType::Mesh1km).to_number(),
+            53394611
+        );
+
+        // 角からわずかに離れた点は、その点を含む地域メッシュとなる
+        let offsets = [
+            (-INNER_OFFSET, -INNER_OFFSET, 53394611),
+            (INNER_OFFSET, -INNER_OFFSET, 53394612),
+            (-INNER_OFFSET, INNER_OFFSET, 53394621),
+            (INNER_OFFSET, INNER_OFFSET, 53394622),
+        ];
+        for (dlng, dlat, expected) in offsets {
+            let coords = Coordinates::new(junction.lng + dlng, junction.lat + dlat);
+            assert_eq!(
+                JPMesh::nearest(coords, JPMeshType::Mesh1km).to_number(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_mesh_from_str() {
+        let cases = [
+            ("5339", JPMeshType::Mesh80km),
+            ("533946", JPMeshType::Mesh10km),
+            ("5339461", JPMeshType::Mesh5km),
+            ("53394611", JPMeshType::Mesh1km),
+            ("533946114", JPMeshType::Mesh500m),
+            ("533946405", JPMeshType::Mesh2km),
+            ("5339461143", JPMeshType::Mesh250m),
+            ("53394611432", JPMeshType::Mesh125m),
+        ];
+
+        for (s, mesh_type) in cases {
+            let mesh: JPMesh = s.parse().unwrap();
+            assert_eq!(mesh.mesh_type(), mesh_type);
+            assert_eq!(mesh.to_string(), s);
+            assert_eq!(format!("{:#}", mesh).parse::<JPMesh>().unwrap(), mesh);
+        }
+
+        let mesh: JPMesh = "00000000".parse().unwrap();
+        assert_eq!(mesh.grid_index(), (0, 0));
+    }
+
+    #[test]
+    fn test_mesh_from_str_invalid() {
+        assert_eq!(
+            "5339a611".parse::<JPMesh>(),
+            Err(JPMeshError::InvalidFormat("5339a611".to_string()))
+        );
+        assert_eq!(
+            "53394".parse::<JPMesh>(),
+            Err(JPMeshError::InvalidCodeLength(5))
+        );
+        assert_eq!(
+            "533946117".parse::<JPMesh>(),
+            Err(JPMeshError::InvalidDigit {
+                position: 8,
+                digit: 7
+            })
+        );
+
+        // 隣の区画と同じ格子上の位置になる桁は受け付けない
+        assert_eq!(
+            "533986".parse::<JPMesh>(),
+            Err(JPMeshError::InvalidDigit {
+                position: 4,
+                digit: 8
+            })
+        );
+        assert_eq!(
+            "9377727945".parse::<JPMesh>(),
+            Err(JPMeshError::InvalidDigit {
+                position: 9,
+                digit: 5
+            })
+        );
+        assert_eq!(
+            "5339-46-11-0".parse::<JPMesh>(),
+            Err(JPMeshError::InvalidDigit {
+                position: 8,
+                digit: 0
+            })
+        );
+
+        // ハイフンは区画の区切りの位置にのみ置くことができる
+        for s in [
+            "5-3-3-9",
+            "-5339-",
+            "53394-611",
+            "5339-",
+            "-5339",
+            "5339--46",
+            "5339-461-1",
+        ] {
+            assert_eq!(
+                s.parse::<JPMesh>(),
+                Err(JPMeshError::InvalidFormat(s.to_string())),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            "533946-11".parse::<JPMesh>(),
+            Ok(JPMesh::from_number(53394611, JPMeshType::Mesh1km))
+        );
+    }
+
+    #[test]
+    fn test_mesh_quadrant_within_parent() {
+        let parent = JPMesh::from_number(53394611, JPMeshType::Mesh1km).to_bounds();
+        let cases = [
+            (533946111, Quadrant::SouthWest),
+            (533946112, Quadrant::SouthEast),
+            (533946113, Quadrant::NorthWest),
+            (533946114, Quadrant::NorthEast),
+        ];
+
+        for (number, quadrant) in cases {
+            let mesh = JPMesh::from_number(number, JPMeshType::Mesh500m);
+            assert_eq!(mesh.quadrant_within_parent(), Some(quadrant));
+
+            let min = mesh.to_bounds().min();
+            let east = min.lng > parent.min().lng;
+            let north = min.lat > parent.min().lat;
+            let expected = match (east, north) {
+                (false, false) => Quadrant::SouthWest,
+                (true, false) => Quadrant::SouthEast,
+                (false, true) => Quadrant::NorthWest,
+                (true, true) => Quadrant::NorthEast,
+            };
+            assert_eq!(quadrant, expected);
+        }
+
+        let mesh = JPMesh::from_number(53394611432, JPMeshType::Mesh125m);
+        assert_eq!(mesh.quadrant_within_parent(), Some(Quadrant::SouthEast));
+        let mesh = JPMesh::from_number(5339464, JPMeshType::Mesh5km);
+        assert_eq!(mesh.quadrant_within_parent(), Some(Quadrant::NorthEast));
+
+        for (number, mesh_type) in [
+            (5339, JPMeshType::Mesh80km),
+            (533946, JPMeshType::Mesh10km),
+            (53394611, JPMeshType::Mesh1km),
+            (533946405, JPMeshType::Mesh2km),
+        ] {
+            let mesh = JPMesh::from_number(number, mesh_type);
+            assert_eq!(mesh.quadrant_within_parent(), None);
+        }
+    }
+
+    #[test]
+    fn test_mesh_parent() {
+        let mesh = JPMesh::from_number(53394611432, JPMeshType::Mesh125m);
+        let parents = [
+            (JPMeshType::Mesh250m, Some(5339461143)),
+            (JPMeshType::Mesh500m, Some(533946114)),
+            (JPMeshType::Mesh1km, Some(53394611)),
+            (JPMeshType::Mesh2km, Some(533946005)),
+            (JPMeshType::Mesh5km, Some(5339461)),
+            (JPMeshType::Mesh10km, Some(533946)),
+            (JPMeshType::Mesh80km, Some(5339)),
+            (JPMeshType::Mesh125m, None),
+        ];
+
+        for (level, expected) in parents {
+            assert_eq!(mesh.parent(level).map(|p| p.to_number()), expected);
+        }
+
+        let mesh = JPMesh::from_number(533946405, JPMeshType::Mesh2km);
+        assert_eq!(mesh.parent(JPMeshType::Mesh5km), None);
+    }
+
+    #[test]
+    fn test_mesh_at_level() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+
+        assert_eq!(mesh.at_level(JPMeshType::Mesh1km), AtLevelResult::Same);
+        assert_eq!(
+            mesh.at_level(JPMeshType::Mesh10km),
+            AtLevelResult::Parent(JPMesh::from_number(533946, JPMeshType::Mesh10km))
+        );
+        assert_eq!(
+            mesh.at_level(JPMeshType::Mesh500m),
+            AtLevelResult::Children(mesh.children(JPMeshType::Mesh500m))
+        );
+
+        let mesh = JPMesh::from_number(5339461, JPMeshType::Mesh5km);
+        assert_eq!(mesh.at_level(JPMeshType::Mesh2km), AtLevelResult::Unrelated);
+    }
+
+    #[test]
+    fn test_mesh_id() {
+        let coords = Coordinates::new(139.767125, 35.681236);
+        for (_, mesh) in JPMesh::all_levels(coords) {
+            let id = mesh.to_id();
+            assert_eq!(JPMesh::from_id(id), Some(mesh));
+            assert_eq!(JPMesh::from_id(MeshId::from_raw(id.raw())), Some(mesh));
+        }
+
+        // 先頭が0のメッシュコードも保持される
+        let mesh = JPMesh::from_grid_index(3, 5, JPMeshType::Mesh1km).unwrap();
+        assert_eq!(JPMesh::from_id(mesh.to_id()), Some(mesh));
+
+        // 同じ種類の中ではメッシュコードの順に並ぶ
+        let a = JPMesh::from_number(53394611, JPMeshType::Mesh1km).to_id();
+        let b = JPMesh::from_number(53394612, JPMeshType::Mesh1km).to_id();
+        assert!(a < b);
+    }
+
+    #[test]
+    fn test_try_from_number_length() {
+        assert_eq!(
+            JPMesh::try_from_number(53394611, JPMeshType::Mesh1km),
+            Ok(JPMesh::from_number(53394611, JPMeshType::Mesh1km))
+        );
+        assert_eq!(
+            JPMesh::try_from_number(533946112, JPMeshType::Mesh1km),
+            Err(JPMeshError::InvalidCodeLength(9))
+        );
+        assert_eq!(
+            JPMesh::try_from_number(5339461, JPMeshType::Mesh1km),
+            Err(JPMeshError::InvalidCodeLength(7))
+        );
+        assert_eq!(
+            JPMesh::try_from_number(0, JPMeshType::Mesh1km),
+            Err(JPMeshError::InvalidCodeLength(1))
+        );
+        assert_eq!(
+            JPMesh::try_from_number(53398611, JPMeshType::Mesh1km),
+            Err(JPMeshError::InvalidDigit {
+                position: 4,
+                digit: 8
+            })
+        );
+        assert_eq!(
+            JPMesh::from_id(MeshId::new(JPMeshType::Mesh5km.tag(), 5339465)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_number_clamps_invalid_code() {
+        // 桁数が多い場合は先頭の桁を残す
+        assert_eq!(
+            JPMesh::from_number(533946112, JPMeshType::Mesh1km),
+            JPMesh::from_number(53394611, JPMeshType::Mesh1km)
+        );
+        // 桁数が少ない場合は末尾を0で補い、分割番号は1に丸める
+        assert_eq!(
+            JPMesh::from_number(53394611, JPMeshType::Mesh500m).to_number(),
+            533946111
+        );
+        // 取りえない値の桁は範囲に丸める
+        assert_eq!(
+            JPMesh::from_number(533996, JPMeshType::Mesh10km).to_number(),
+            533976
+        );
+        assert_eq!(
+            JPMesh::from_number(53394619, JPMeshType::Mesh1km).to_number(),
+            53394619
+        );
+        assert_eq!(
+            JPMesh::from_number(5339461150, JPMeshType::Mesh250m).to_number(),
+            5339461141
+        );
+    }
+
+    #[test]
+    fn test_parent_code_number() {
+        let mesh = JPMesh::from_number(53394611213, JPMeshType::Mesh125m);
+        assert_eq!(mesh.parent_code_number(JPMeshType::Mesh1km), Some(53394611));
+        assert_eq!(
+            mesh.parent_code_number(JPMeshType::Mesh125m),
+            Some(53394611213)
+        );
+        assert_eq!(mesh.parent_code_number(JPMeshType::Mesh2km), None);
+
+        for level in JPMeshType::all() {
+            if let Some(number) = mesh.parent_code_number(level) {
+                assert_eq!(
+                    mesh.parent(level).map(|p| p.to_number()).unwrap_or(number),
+                    number
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_geojson_feature_with() {
+        #[derive(serde::Serialize)]
+        struct Stats {
+            population: u32,
+            households: u32,
+        }
+
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let feature = mesh
+            .to_geojson_feature_with(&Stats {
+                population: 1200,
+                households: 600,
+            })
+            .unwrap();
+
+        assert_eq!(feature["type"], "Feature");
+        assert_eq!(feature["properties"]["mesh_code"], 53394611);
+        assert_eq!(feature["properties"]["population"], 1200);
+        assert_eq!(feature["properties"]["households"], 600);
+        assert_eq!(feature["geometry"]["type"], "Polygon");
+
+        assert!(matches!(
+            mesh.to_geojson_feature_with(&1200),
+            Err(JPMeshError::InvalidProperties(_))
+        ));
+        let mut invalid = std::collections::BTreeMap::new();
+        invalid.insert((1, 2), 3);
+        assert!(matches!(
+            mesh.to_geojson_feature_with(&invalid),
+            Err(JPMeshError::InvalidProperties(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_geojson_geometry() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let geometry = mesh.to_geojson_geometry();
+
+        assert_eq!(geometry["type"], "Polygon");
+        assert_eq!(geometry.as_object().unwrap().len(), 2);
+        let ring = geometry["coordinates"][0].as_array().unwrap();
+        assert_eq!(ring.len(), 5);
+        assert_eq!(
+            ring[0],
+            serde_json::json!(mesh.to_bounds().min().as_array())
+        );
+        assert_eq!(ring[0], ring[4]);
+
+        let feature = mesh
+            .to_geojson_feature_with(&serde_json::Map::new())
+            .unwrap();
+        assert_eq!(feature["geometry"], geometry);
+    }
+
+    #[test]
+    fn test_iter_on_bounds_filtered() {
+        let bounds = Rect::new(
+            Coordinates::new(139.70, 35.60),
+            Coordinates::new(139.80, 35.70),
+        );
+        let all = JPMesh::from_on_bounds(bounds, JPMeshType::Mesh1km);
+        let even = JPMesh::iter_on_bounds_filtered(bounds, JPMeshType::Mesh1km, |mesh| {
+            mesh.to_number() % 2 == 0
+        })
+        .collect::<Vec<_>>();
+
+        assert!(!even.is_empty());
+        assert_eq!(
+            even,
+            all.into_iter()
+                .filter(|mesh| mesh.to_number() % 2 == 0)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_scale_factor() {
+        use JPMeshType::*;
+
+        assert_eq!(JPMesh::scale_factor(Mesh80km, Mesh1km), Some((80, 80)));
+        assert_eq!(JPMesh::scale_factor(Mesh1km, Mesh80km), Some((80, 80)));
+        assert_eq!(JPMesh::scale_factor(Mesh1km, Mesh125m), Some((8, 8)));
+        assert_eq!(JPMesh::scale_factor(Mesh1km, Mesh1km), Some((1, 1)));
+        assert_eq!(JPMesh::scale_factor(Mesh5km, Mesh2km), None);
+    }
+
+    #[test]
+    fn test_is_defined() {
+        let tokyo = JPMesh::new(
+            Coordinates::new(139.767125, 35.681236),
+            JPMeshType::Mesh125m,
+        );
+        assert!(tokyo.is_defined());
+        assert!(tokyo.parent(JPMeshType::Mesh80km).unwrap().is_defined());
+
+        // 太平洋上
+        let ocean = JPMesh::new(Coordinates::new(150.5, 30.5), JPMeshType::Mesh1km);
+        assert!(!ocean.is_defined());
+
+        let primaries = JPMesh::japan_primary_meshes().collect::<Vec<_>>();
+        assert_eq!(primaries.len(), 176);
+        assert!(primaries.is_sorted_by_key(|mesh| mesh.to_number()));
+        assert!(primaries.iter().all(|mesh| mesh.is_defined()));
+    }
+
+    #[test]
+    fn test_nearest_defined() {
+        let tokyo = Coordinates::new(139.767125, 35.681236);
+        assert_eq!(
+            JPMesh::nearest_defined(tokyo, JPMeshType::Mesh80km, 0),
+            Some(JPMesh::new(tokyo, JPMeshType::Mesh80km))
+        );
+
+        // 太平洋上: 第1次地域区画 4341 (北緯28度40分〜29度20分, 東経141〜142度) は日本の範囲に含まれない
+        let ocean = Coordinates::new(141.5, 29.0);
+        assert_eq!(
+            JPMesh::nearest_defined(ocean, JPMeshType::Mesh80km, 0),
+            None
+        );
+        let found = JPMesh::nearest_defined(ocean, JPMeshType::Mesh80km, 10).unwrap();
+        assert!(found.is_defined());
+        let ring = found
+            .chebyshev_distance(&JPMesh::new(ocean, JPMeshType::Mesh80km))
+            .unwrap();
+        assert!(
+            (0..ring as u32)
+                .all(|k| JPMesh::nearest_defined(ocean, JPMeshType::Mesh80km, k).is_none())
+        );
+    }
+
+    #[test]
+    fn test_covering_web_tiles() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(mesh.covering_web_tiles(10), vec![(909, 403)]);
+        assert_eq!(mesh.covering_web_tiles(0), vec![(0, 0)]);
+
+        // 第1次地域区画 (経度1度, 緯度40分) は z=10 で約3×3枚のタイルと重なる
+        let primary = JPMesh::from_number(5339, JPMeshType::Mesh80km);
+        let tiles = primary.covering_web_tiles(10);
+        assert!((9..=16).contains(&tiles.len()), "{:?}", tiles);
+        assert!(tiles.contains(&(909, 403)));
+
+        assert!(
+            mesh.covering_web_tiles(crate::MAX_WEB_TILE_ZOOM + 1)
+                .is_empty()
+        );
+        assert!(mesh.covering_web_tiles(u8::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_in_web_tile() {
+        // 東京駅を含む z=12 のタイル (経度約0.088度, 緯度約0.071度)
+        let meshes = JPMesh::in_web_tile(3638, 1612, 12, JPMeshType::Mesh1km);
+        assert!((56..=90).contains(&meshes.len()), "{}", meshes.len());
+        assert!(meshes.contains(&JPMesh::from_number(53394611, JPMeshType::Mesh1km)));
+        assert!(
+            meshes
+                .iter()
+                .all(|mesh| mesh.covering_web_tiles(12).contains(&(3638, 1612)))
+        );
+
+        assert!(JPMesh::in_web_tile(4096, 1612, 12, JPMeshType::Mesh1km).is_empty());
+        assert!(
+            JPMesh::in_web_tile(0, 0, crate::MAX_WEB_TILE_ZOOM + 1, JPMeshType::Mesh1km).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_iter_clipped() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let cell = mesh.to_bounds();
+        let quarter = |cell: &Rect| {
+            Coordinates::new(
+                cell.min().lng + JPMeshType::Mesh1km.lng_interval() / 4.0,
+                cell.min().lat + JPMeshType::Mesh1km.lat_interval() / 4.0,
+            )
+        };
+
+        // 東西に3区画、南北に1区画を少しはみ出す矩形
+        let east = mesh.shift(2, 1).unwrap().to_bounds();
+        let bounds = Rect::new(quarter(&cell), quarter(&east));
+        let clipped = JPMesh::iter_clipped(bounds, JPMeshType::Mesh1km).collect::<Vec<_>>();
+        assert_eq!(
+            clipped.iter().map(|(mesh, _)| *mesh).collect::<Vec<_>>(),
+            JPMesh::from_on_bounds(bounds, JPMeshType::Mesh1km)
+        );
+        assert_eq!(clipped.len(), 6);
+
+        for (mesh, rect) in &clipped {
+            assert_eq!(Some(*rect), mesh.to_bounds().intersection(&bounds));
+        }
+        assert_eq!(clipped[0].1, Rect::new(quarter(&cell), cell.max()));
+
+        // 矩形に完全に含まれる地域メッシュは、範囲そのものとなる
+        let wide = Rect::new(
+            quarter(&cell),
+            quarter(&mesh.shift(3, 3).unwrap().to_bounds()),
+        );
+        let inner = mesh.shift(1, 1).unwrap();
+        assert!(
+            JPMesh::iter_clipped(wide, JPMeshType::Mesh1km)
+                .any(|(mesh, rect)| mesh == inner && rect == inner.to_bounds())
+        );
+    }
+
+    #[test]
+    fn test_diagnose_number() {
+        use JPMeshType::*;
+
+        let valid = [
+            (5339, Mesh80km),
+            (533946, Mesh10km),
+            (5339462, Mesh5km),
+            (533946005, Mesh2km),
+            (53394611, Mesh1km),
+            (533946113, Mesh500m),
+            (5339461134, Mesh250m),
+            (53394611341, Mesh125m),
+        ];
+        for (n, mesh_type) in valid {
+            assert_eq!(JPMesh::diagnose_number(n), Ok(mesh_type), "{}", n);
+        }
+
+        let invalid = [
+            // 桁数
+            (53394, JPMeshError::InvalidCodeLength(5)),
+            (533946113412, JPMeshError::InvalidCodeLength(12)),
+            // 第1次地域区画の経度 (東経180度以東)
+            (
+                5389,
+                JPMeshError::InvalidDigit {
+                    position: 2,
+                    digit: 8,
+                },
+            ),
+            // 第2次地域区画の分割番号
+            (
+                533986,
+                JPMeshError::InvalidDigit {
+                    position: 4,
+                    digit: 8,
+                },
+            ),
+            (
+                53394911,
+                JPMeshError::InvalidDigit {
+                    position: 5,
+                    digit: 9,
+                },
+            ),
+            // 5倍地域メッシュの分割番号
+            (
+                5339465,
+                JPMeshError::InvalidDigit {
+                    position: 6,
+                    digit: 5,
+                },
+            ),
+            // 2倍地域メッシュの桁
+            (
+                533946315,
+                JPMeshError::InvalidDigit {
+                    position: 6,
+                    digit: 3,
+                },
+            ),
+            // 2分の1以下の地域メッシュの分割番号
+            (
+                533946116,
+                JPMeshError::InvalidDigit {
+                    position: 8,
+                    digit: 6,
+                },
+            ),
+            (
+                5339461130,
+                JPMeshError::InvalidDigit {
+                    position: 9,
+                    digit: 0,
+                },
+            ),
+            (
+                53394611345,
+                JPMeshError::InvalidDigit {
+                    position: 10,
+                    digit: 5,
+                },
+            ),
+            // 日本の範囲外
+            (4341, JPMeshError::UndefinedPrimaryMesh(4341)),
+            (43410011, JPMeshError::UndefinedPrimaryMesh(4341)),
+        ];
+        for (n, err) in invalid {
+            assert_eq!(JPMesh::diagnose_number(n), Err(err), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let east = JPMesh::from_number(53394612, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+        let (min, max, center) = (bounds.min(), bounds.max(), bounds.center());
+
+        assert_eq!(mesh.classify(center), CellMembership::Inside);
+
+        // 南端・西端の辺上は範囲に含まれるが、境界上とする
+        for coords in [
+            min,
+            Coordinates::new(min.lng, center.lat),
+            Coordinates::new(center.lng, min.lat),
+        ] {
+            assert_eq!(mesh.classify(coords), CellMembership::OnBoundary);
+            assert!(mesh.is_inside(coords));
+        }
+
+        // 北端・東端の辺上は範囲に含まれないが、境界上とする
+        for coords in [
+            max,
+            Coordinates::new(max.lng, center.lat),
+            Coordinates::new(center.lng, max.lat),
+        ] {
+            assert_eq!(mesh.classify(coords), CellMembership::OnBoundary);
+            assert!(!mesh.is_inside(coords));
+        }
+
+        // 隣接する地域メッシュが共有する辺は、どちらでも境界上となる
+        let shared = Coordinates::new(max.lng, center.lat);
+        assert_eq!(east.classify(shared), CellMembership::OnBoundary);
+        assert!(east.is_inside(shared));
+
+        assert_eq!(
+            mesh.classify(Coordinates::new(max.lng + INNER_OFFSET, center.lat)),
+            CellMembership::Outside
+        );
+        assert_eq!(
+            mesh.classify(Coordinates::new(f64::NAN, center.lat)),
+            CellMembership::Outside
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        let coords = Coordinates::new(139.767125, 35.681236);
+        for mesh_type in JPMeshType::all() {
+            assert_eq!(
+                JPMesh::try_new(coords, mesh_type),
+                Ok(JPMesh::new(coords, mesh_type))
+            );
+        }
+
+        // ラジアンのまま指定した座標は範囲外となる
+        let (lng, lat) = coords.to_radians();
+        assert_eq!(
+            JPMesh::try_new(Coordinates::new(lng, lat), JPMeshType::Mesh1km),
+            Err(JPMeshError::OutOfDomain)
+        );
+        assert_eq!(
+            JPMesh::try_new(Coordinates::from_radians(lng, lat), JPMeshType::Mesh1km),
+            Ok(JPMesh::new(coords, JPMeshType::Mesh1km))
+        );
+
+        assert_eq!(
+            JPMesh::try_new(Coordinates::new(139.0, 70.0), JPMeshType::Mesh1km),
+            Err(JPMeshError::OutOfDomain)
+        );
+        assert_eq!(
+            JPMesh::try_new(Coordinates::new(f64::NAN, 35.0), JPMeshType::Mesh1km),
+            Err(JPMeshError::OutOfDomain)
+        );
+    }
+
+    #[test]
+    fn test_iter_on_bounds_ordered() {
+        let origin = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let (col, row) = origin.grid_index();
+        let at = |dcol: u64, drow: u64| {
+            JPMesh::from_grid_index(col + dcol, row + drow, JPMeshType::Mesh1km).unwrap()
+        };
+        // 東西3区画, 南北2区画
+        let bounds = Rect::new(origin.to_bounds().min(), at(2, 1).to_bounds().center());
+
+        let ordered = |order| {
+            JPMesh::iter_on_bounds_ordered(bounds, JPMeshType::Mesh1km, order).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            ordered(TraversalOrder::RowMajor),
+            [at(0, 0), at(1, 0), at(2, 0), at(0, 1), at(1, 1), at(2, 1)]
+        );
+        assert_eq!(
+            ordered(TraversalOrder::RowMajor),
+            JPMesh::from_on_bounds(bounds, JPMeshType::Mesh1km)
+        );
+        assert_eq!(
+            ordered(TraversalOrder::ColumnMajor),
+            [at(0, 0), at(0, 1), at(1, 0), at(1, 1), at(2, 0), at(2, 1)]
+        );
+        let serpentine = ordered(TraversalOrder::Serpentine);
+        assert_eq!(
+            serpentine,
+            [at(0, 0), at(1, 0), at(2, 0), at(2, 1), at(1, 1), at(0, 1)]
+        );
+        assert!(
+            serpentine
+                .windows(2)
+                .all(|pair| pair[0].manhattan_distance(&pair[1]) == Some(1))
+        );
+    }
+
+    #[test]
+    fn test_weighted_center() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+        let (min, center) = (bounds.min(), bounds.center());
+        let inner = Coordinates::new(min.lng + INNER_OFFSET, min.lat + INNER_OFFSET);
+        let outside = Coordinates::new(bounds.max().lng + INNER_OFFSET, center.lat);
+
+        assert_eq!(mesh.weighted_center(&[]), None);
+        assert_eq!(mesh.weighted_center(&[(outside, 10.0)]), None);
+        assert_eq!(mesh.weighted_center(&[(center, 0.0)]), None);
+
+        // 範囲外の標本は無視し、重みの大きい標本に近づく
+        let weighted = mesh
+            .weighted_center(&[(center, 1.0), (inner, 3.0), (outside, 100.0)])
+            .unwrap();
+        assert_approx_eq!(weighted.lng, (center.lng + inner.lng * 3.0) / 4.0);
+        assert_approx_eq!(weighted.lat, (center.lat + inner.lat * 3.0) / 4.0);
+
+        // 重みが等しい場合は重心と一致する
+        let points = [center, inner];
+        let equal = mesh.weighted_center(&points.map(|p| (p, 2.0))).unwrap();
+        assert_eq!(Some(equal), crate::centroid(&points));
+    }
+
+    #[test]
+    fn test_shift() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(mesh.shift(0, 0), Some(mesh));
+        assert_eq!(
+            mesh.shift(1, 0),
+            Some(JPMesh::from_number(53394612, JPMeshType::Mesh1km))
+        );
+        assert_eq!(
+            mesh.shift(-2, 1),
+            Some(JPMesh::from_number(53394529, JPMeshType::Mesh1km))
+        );
+        assert_eq!(mesh.shift(3, 0), mesh.walk_east(3).last());
+        assert_eq!(mesh.shift(0, -3), mesh.walk_south(3).last());
+
+        let (col, row) = mesh.grid_index();
+        assert_eq!(mesh.shift(-(col as i64), 0).unwrap().grid_index(), (0, row));
+        assert_eq!(mesh.shift(-(col as i64) - 1, 0), None);
+        assert_eq!(mesh.shift(0, i64::MAX), None);
+        assert_eq!(mesh.shift(i64::MIN, 0), None);
+    }
+
+    #[test]
+    fn test_domain_corners() {
+        let origin = Coordinates::new(100.0, 0.0);
+        let north_east = Coordinates::new(180.0, 66.0 + 40.0 / 60.0);
+        let last = Coordinates::new(north_east.lng.next_down(), north_east.lat.next_down());
+
+        let expected = [
+            (JPMeshType::Mesh80km, "0000", "9979"),
+            (JPMeshType::Mesh10km, "000000", "997977"),
+            (JPMeshType::Mesh5km, "0000001", "9979774"),
+            (JPMeshType::Mesh2km, "000000005", "997977885"),
+            (JPMeshType::Mesh1km, "00000000", "99797799"),
+            (JPMeshType::Mesh500m, "000000001", "997977994"),
+            (JPMeshType::Mesh250m, "0000000011", "9979779944"),
+            (JPMeshType::Mesh125m, "00000000111", "99797799444"),
+        ];
+        for (mesh_type, first_code, last_code) in expected {
+            let first = JPMesh::new(origin, mesh_type);
+            assert_eq!(first.to_string(), first_code);
+            assert_eq!(first.grid_index(), (0, 0));
+            assert_eq!(first.to_bounds().min(), origin);
+            assert_eq!(JPMesh::try_new(origin, mesh_type), Ok(first));
+
+            let mesh = JPMesh::new(last, mesh_type);
+            let (cols, rows) = mesh_type.grid_size();
+            assert_eq!(mesh.to_string(), last_code);
+            assert_eq!(mesh.grid_index(), (cols - 1, rows - 1));
+            assert_eq!(mesh.to_bounds().max().lng, 180.0);
+            assert_approx_eq!(mesh.to_bounds().max().lat, north_east.lat);
+            assert!(mesh.to_bounds().includes(last));
+            assert_eq!(JPMesh::try_new(last, mesh_type), Ok(mesh));
+
+            // 北東端の辺上は範囲に含まれない
+            for coords in [
+                Coordinates::new(180.0, 35.0),
+                Coordinates::new(140.0, north_east.lat),
+                north_east,
+            ] {
+                assert_eq!(
+                    JPMesh::try_new(coords, mesh_type),
+                    Err(JPMeshError::OutOfDomain)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_coverage() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+        let (min, max, center) = (bounds.min(), bounds.max(), bounds.center());
+
+        // 地域メッシュを含む多角形・含まない多角形
+        let outer = bounds.union(&mesh.shift(1, 1).unwrap().to_bounds());
+        assert_eq!(mesh.polygon_coverage(&outer.as_polygon(false), 4), 1.0);
+        let other = mesh.shift(2, 0).unwrap().to_bounds();
+        assert_eq!(mesh.polygon_coverage(&other.as_polygon(false), 4), 0.0);
+
+        // 西半分を覆う多角形
+        let west = Rect::new(
+            Coordinates::new(min.lng - 1.0, min.lat - 1.0),
+            Coordinates::new(center.lng, max.lat + 1.0),
+        );
+        assert_eq!(mesh.polygon_coverage(&west.as_polygon(true), 8), 0.5);
+
+        // 南西の頂点を通る対角線で切った三角形
+        let triangle = [min, Coordinates::new(max.lng, min.lat), max];
+        assert!((mesh.polygon_coverage(&triangle, 64) - 0.5).abs() < 0.02);
+
+        assert_eq!(mesh.polygon_coverage(&outer.as_polygon(false), 0), 0.0);
+    }
+
+    /// すべてのメッシュの種類で、座標からの生成・メッシュコードとの相互変換・範囲の大きさが整合することを確認します。
+    #[test]
+    fn test_every_type_round_trips() {
+        // 種類を追加した場合に、このテストと `JPMeshType::all` の更新を忘れないようにする
+        let covered = |mesh_type: JPMeshType| match mesh_type {
+            JPMeshType::Mesh80km
+            | JPMeshType::Mesh10km
+            | JPMeshType::Mesh5km
+            | JPMeshType::Mesh2km
+            | JPMeshType::Mesh1km
+            | JPMeshType::Mesh500m
+            | JPMeshType::Mesh250m
+            | JPMeshType::Mesh125m => JPMeshType::all().contains(&mesh_type),
+        };
+
+        let points = [
+            Coordinates::new(139.767125, 35.681236),
+            Coordinates::new(141.350755, 43.068661),
+            Coordinates::new(127.681314, 26.212401),
+        ];
+        for mesh_type in JPMeshType::all() {
+            assert!(covered(mesh_type));
+            for coords in points {
+                let mesh = JPMesh::new(coords, mesh_type);
+                assert_eq!(mesh.mesh_type(), mesh_type);
+
+                let number = mesh.to_number();
+                assert_eq!(JPMesh::from_number(number, mesh_type), mesh);
+                assert_eq!(JPMesh::try_from_number(number, mesh_type), Ok(mesh));
+                assert_eq!(number.to_string().len(), mesh_type.code_length());
+
+                let bounds = mesh.to_bounds();
+                assert!(bounds.includes(coords));
+                assert_approx_eq!(
+                    bounds.max().lng - bounds.min().lng,
+                    mesh_type.lng_interval()
+                );
+                assert_approx_eq!(
+                    bounds.max().lat - bounds.min().lat,
+                    mesh_type.lat_interval()
+                );
+                assert_eq!(JPMesh::new(bounds.center(), mesh_type), mesh);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounds_expanded_cells() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+        assert_eq!(mesh.bounds_expanded_cells(0.0), bounds);
+
+        // 1区画分広げると 3×3 区画の範囲になる
+        let expanded = mesh.bounds_expanded_cells(1.0);
+        let block = mesh
+            .shift(-1, -1)
+            .unwrap()
+            .to_bounds()
+            .union(&mesh.shift(1, 1).unwrap().to_bounds());
+        assert_approx_eq!(expanded.min().lng, block.min().lng);
+        assert_approx_eq!(expanded.min().lat, block.min().lat);
+        assert_approx_eq!(expanded.max().lng, block.max().lng);
+        assert_approx_eq!(expanded.max().lat, block.max().lat);
+
+        // 境界のわずかに外側の座標を含む
+        let outside = Coordinates::new(bounds.max().lng + 1e-9, bounds.center().lat);
+        assert!(!bounds.includes(outside));
+        assert!(mesh.bounds_expanded_cells(0.01).includes(outside));
+
+        // 負の値や NaN では矩形を狭めない
+        assert_eq!(mesh.bounds_expanded_cells(-1.0), bounds);
+        assert_eq!(mesh.bounds_expanded_cells(f64::NAN), bounds);
+    }
+
+    #[test]
+    fn test_bin_indices() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let other = mesh.shift(1, 0).unwrap();
+        let coords: Vec<Coordinates> = [mesh, other, mesh, mesh, other]
+            .iter()
+            .zip([0.2, 0.4, 0.6, 0.8, 0.5])
+            .map(|(mesh, t)| {
+                let bounds = mesh.to_bounds();
+                Coordinates::new(
+                    bounds.min().lng + (bounds.max().lng - bounds.min().lng) * t,
+                    bounds.min().lat + (bounds.max().lat - bounds.min().lat) * t,
+                )
+            })
+            .collect();
+
+        let bins = JPMesh::bin_indices(&coords, JPMeshType::Mesh1km);
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[&mesh], vec![0, 2, 3]);
+        assert_eq!(bins[&other], vec![1, 4]);
+
+        // 番号の列は 0..coords.len() を分割する
+        let mut indices: Vec<usize> = bins.values().flatten().copied().collect();
+        indices.sort();
+        assert_eq!(indices, (0..coords.len()).collect::<Vec<_>>());
+
+        // 範囲外の座標は含まれない
+        let mut with_outside = coords.clone();
+        with_outside.push(Coordinates::new(0.0, 0.0));
+        let bins = JPMesh::bin_indices(&with_outside, JPMeshType::Mesh1km);
+        assert_eq!(bins.values().map(Vec::len).sum::<usize>(), coords.len());
+
+        assert!(JPMesh::bin_indices(&[], JPMeshType::Mesh1km).is_empty());
+    }
+
+    #[test]
+    fn test_level_number_and_name() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(mesh.level_number(), Some(3));
+        assert_eq!(mesh.level_name_ja(), "基準地域メッシュ");
+
+        let fine = JPMesh::from_number(53394611213, JPMeshType::Mesh125m);
+        assert_eq!(fine.level_number(), Some(6));
+        assert_eq!(fine.level_name_ja(), "8分の1地域メッシュ");
+
+        let special = JPMesh::from_number(533946405, JPMeshType::Mesh2km);
+        assert_eq!(special.level_number(), None);
+        assert_eq!(special.level_name_ja(), "2倍地域メッシュ");
+
+        // 名称は文字列からの変換で種類に戻る
+        for mesh_type in JPMeshType::all() {
+            let mesh = JPMesh::new(Coordinates::new(139.767125, 35.681236), mesh_type);
+            assert_eq!(mesh.level_name_ja().parse::<JPMeshType>(), Ok(mesh_type));
+        }
+    }
+
+    #[test]
+    fn test_mesh_decomposition() {
+        let coords = Coordinates::new(139.767125, 35.681236);
+        let decomposition = MeshDecomposition::new(coords);
+        assert_eq!(decomposition.coords(), coords);
+        for mesh_type in JPMeshType::all() {
+            assert_eq!(
+                decomposition.code(mesh_type),
+                JPMesh::new(coords, mesh_type)
+            );
+        }
+
+        let finest = decomposition.code(JPMeshType::Mesh125m).to_number();
+        let digits = decomposition.digits();
+        assert_eq!(
+            digits.iter().fold(0, |n, digit| n * 10 + *digit as u64),
+            finest
+        );
+        assert_eq!(&digits[..8], &[5, 3, 3, 9, 4, 6, 1, 1]);
+
+        // 先頭の桁が0となる座標
+        let south = MeshDecomposition::new(Coordinates::new(100.5, 0.5));
+        assert_eq!(south.digits()[..4], [0, 0, 0, 0]);
+
+        // 5倍・2倍地域メッシュの桁は基準地域メッシュの桁から求める
+        for (lng, lat) in [
+            (139.70, 35.60),
+            (139.76, 35.66),
+            (140.123, 36.789),
+            (100.0, 0.0),
+        ] {
+            let coords = Coordinates::new(lng, lat);
+            let decomposition = MeshDecomposition::new(coords);
+            for mesh_type in [JPMeshType::Mesh5km, JPMeshType::Mesh2km] {
+                assert_eq!(
+                    decomposition.code(mesh_type),
+                    JPMesh::new(coords, mesh_type),
+                    "{:?} {:?}",
+                    coords,
+                    mesh_type
+                );
+            }
+        }
+
+        // 範囲外の座標は JPMesh::new と同じ結果となる
+        let outside = Coordinates::new(99.5, 35.0);
+        let decomposition = MeshDecomposition::new(outside);
+        for mesh_type in JPMeshType::all() {
+            assert_eq!(
+                decomposition.code(mesh_type),
+                JPMesh::new(outside, mesh_type)
+            );
+        }
+    }
+
+    #[test]
+    fn test_neighbor_numbers() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(
+            mesh.neighbor_numbers(),
+            vec![
+                53394600, 53394601, 53394602, 53394610, 53394612, 53394620, 53394621, 53394622
+            ]
+        );
+
+        let expected: Vec<u64> = mesh
+            .disk(1)
+            .into_iter()
+            .filter(|neighbor| *neighbor != mesh)
+            .map(|neighbor| neighbor.to_number())
+            .collect();
+        assert_eq!(mesh.neighbor_numbers(), expected);
+
+        // 範囲の南西端では3区画のみ
+        let corner = JPMesh::from_grid_index(0, 0, JPMeshType::Mesh2km).unwrap();
+        assert_eq!(corner.neighbor_numbers().len(), 3);
+    }
+
+    #[test]
+    fn test_to_ewkb() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let ewkb = mesh.to_ewkb();
+        assert_eq!(ewkb, mesh.to_wkb(Some(JPMESH_SRID)));
+        assert_eq!(
+            u32::from_le_bytes(ewkb[5..9].try_into().unwrap()),
+            JPMESH_SRID
+        );
+        assert_eq!(JPMesh::assumed_crs(), format!("EPSG:{}", JPMESH_SRID));
+    }
+
+    #[test]
+    fn test_new_matches_try_new() {
+        for mesh_type in JPMeshType::all() {
+            for coords in [
+                Coordinates::new(139.767125, 35.681236),
+                Coordinates::new(139.75, 35.0 + 2.0 / 3.0),
+                Coordinates::new(100.0, 0.0),
+            ] {
+                assert_eq!(
+                    Ok(JPMesh::new(coords, mesh_type)),
+                    JPMesh::try_new(coords, mesh_type)
+                );
+            }
+        }
+
+        // 範囲外の座標は各桁への分解によって求める
+        let coords = Coordinates::new(99.5, 35.0);
+        assert!(JPMesh::try_new(coords, JPMeshType::Mesh1km).is_err());
+        assert_eq!(
+            JPMesh::new(coords, JPMeshType::Mesh1km),
+            build_mesh!(JPMeshType::Mesh1km, |Repr| Repr::from_coordinates(
+                coords,
+                JPMeshType::Mesh1km
+            ))
+        );
+    }
+
+    #[test]
+    fn test_into_500m() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let children = mesh.into_500m().unwrap();
+
+        assert_eq!(
+            children.map(|c| c.to_number()),
+            [533946111, 533946112, 533946113, 533946114]
+        );
+        assert_eq!(children.to_vec(), mesh.children(JPMeshType::Mesh500m));
+        assert!(
+            children
+                .iter()
+                .all(|c| c.parent(JPMeshType::Mesh1km) == Some(mesh))
+        );
+
+        let bounds = mesh.to_bounds();
+        let union = children
+            .iter()
+            .flat_map(|c| c.to_bounds().iter_corners())
+            .collect::<Option<Rect>>();
+        assert_eq!(union, Some(bounds));
+
+        assert_eq!(
+            JPMesh::from_number(533946, JPMeshType::Mesh10km).into_500m(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_center() {
+        let coords = Coordinates::new(139.767125, 35.681236);
+        for (mesh_type, mesh) in JPMesh::all_levels(coords) {
+            let center = mesh.to_bounds().center();
+            assert_eq!(JPMesh::from_center(center, mesh_type), mesh);
+        }
+    }
+
+    #[test]
+    fn test_coverage_ratio() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let bounds = mesh.to_bounds();
+
+        assert_eq!(mesh.coverage_ratio(&bounds), 1.0);
+
+        let larger = Rect::new(Coordinates::new(139.0, 35.0), Coordinates::new(140.0, 36.0));
+        assert_eq!(mesh.coverage_ratio(&larger), 1.0);
+
+        let disjoint = Rect::new(Coordinates::new(130.0, 30.0), Coordinates::new(131.0, 31.0));
+        assert_eq!(mesh.coverage_ratio(&disjoint), 0.0);
+
+        // 西半分
+        let half = Rect::new(
+            bounds.min(),
+            Coordinates::new(bounds.center().lng, bounds.max().lat),
+        );
+        assert_approx_eq!(mesh.coverage_ratio(&half), 0.5);
+    }
+
+    #[test]
+    fn test_siblings() {
+        let mesh = JPMesh::from_number(533946113, JPMeshType::Mesh500m);
+
+        let siblings = mesh.siblings(JPMeshType::Mesh1km);
+        assert_eq!(
+            siblings.iter().map(|m| m.to_number()).collect::<Vec<_>>(),
+            vec![533946111, 533946112, 533946114]
+        );
+
+        let siblings = mesh.siblings(JPMeshType::Mesh10km);
+        assert_eq!(siblings.len(), 20 * 20 - 1);
+        assert!(!siblings.contains(&mesh));
+
+        assert!(mesh.siblings(JPMeshType::Mesh125m).is_empty());
+    }
+
+    #[test]
+    fn test_2km_validation() {
+        let mesh = JPMesh::try_from_number(533946405, JPMeshType::Mesh2km).unwrap();
+        assert_eq!("533946405".parse::<JPMesh>(), Ok(mesh));
+        assert!(mesh.to_bounds().includes(mesh.to_bounds().center()));
+
+        let malformed = JPMeshError::InvalidDigit {
+            position: 7,
+            digit: 1,
+        };
+        assert_eq!(
+            JPMesh::try_from_number(533946415, JPMeshType::Mesh2km),
+            Err(malformed.clone())
+        );
+        assert_eq!("5339-46-41-5".parse::<JPMesh>(), Err(malformed));
+    }
+
+    #[test]
+    fn test_disk() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let disk = mesh.disk(2);
+        assert_eq!(disk.len(), 25);
+        assert_eq!(disk[12], mesh);
+        assert_eq!(mesh.disk(0), vec![mesh]);
+
+        // 範囲の端では範囲外の地域メッシュを含まない
+        let origin = JPMesh::from_grid_index(0, 0, JPMeshType::Mesh1km).unwrap();
+        assert_eq!(origin.disk(1).len(), 4);
+    }
+
+    #[test]
+    fn test_is_edge_of_domain() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert!(!mesh.is_edge_of_domain());
+        assert_eq!(mesh.disk(1).len(), 9);
+
+        for mesh_type in JPMeshType::all() {
+            let (cols, rows) = mesh_type.grid_size();
+            for (col, row) in [(0, 5), (5, 0), (cols - 1, 5), (5, rows - 1)] {
+                let mesh = JPMesh::from_grid_index(col, row, mesh_type).unwrap();
+                assert!(mesh.is_edge_of_domain());
+                assert!(mesh.disk(1).len() < 9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_common_ancestor() {
+        let a = JPMesh::from_number(53394611111, JPMeshType::Mesh125m);
+        let b = JPMesh::from_number(53394611444, JPMeshType::Mesh125m);
+        assert_eq!(
+            a.common_ancestor(&b),
+            Some(JPMesh::from_number(53394611, JPMeshType::Mesh1km))
+        );
+
+        let c = JPMesh::from_number(53394611114, JPMeshType::Mesh125m);
+        assert_eq!(
+            a.common_ancestor(&c),
+            Some(JPMesh::from_number(5339461111, JPMeshType::Mesh250m))
+        );
+        assert_eq!(a.common_ancestor(&a), Some(a));
+
+        // 異なる階層の地域メッシュ同士
+        let far = JPMesh::from_number(53390000, JPMeshType::Mesh1km);
+        assert_eq!(
+            a.common_ancestor(&far),
+            Some(JPMesh::from_number(5339, JPMeshType::Mesh80km))
+        );
+        let km2 = JPMesh::from_number(533946005, JPMeshType::Mesh2km);
+        assert_eq!(
+            a.common_ancestor(&km2),
+            Some(JPMesh::from_number(533946, JPMeshType::Mesh10km))
+        );
+
+        let other = JPMesh::from_number(64414277, JPMeshType::Mesh1km);
+        assert_eq!(a.common_ancestor(&other), None);
+    }
+
+    #[test]
+    fn test_grid_distance() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let east = mesh.shift(1, 0).unwrap();
+        let north_east = mesh.shift(1, 1).unwrap();
+        let far = mesh.shift(-7, 3).unwrap();
+
+        assert_eq!(mesh.chebyshev_distance(&mesh), Some(0));
+        assert_eq!(mesh.chebyshev_distance(&east), Some(1));
+        assert_eq!(mesh.manhattan_distance(&east), Some(1));
+        assert_eq!(mesh.chebyshev_distance(&north_east), Some(1));
+        assert_eq!(mesh.manhattan_distance(&north_east), Some(2));
+        assert_eq!(mesh.chebyshev_distance(&far), Some(7));
+        assert_eq!(far.manhattan_distance(&mesh), Some(10));
+
+        let other = JPMesh::from_number(533946, JPMeshType::Mesh10km);
+        assert_eq!(mesh.chebyshev_distance(&other), None);
+        assert_eq!(mesh.manhattan_distance(&other), None);
+    }
+
+    #[test]
+    fn test_new_with_options() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let min = mesh.to_bounds().min();
+        let opts = MeshOptions {
+            boundary_epsilon: 1e-9,
+        };
+
+        // 既定の設定では境界上の座標は北東側
+        assert_eq!(
+            JPMesh::new_with_options(min, JPMeshType::Mesh1km, MeshOptions::default()),
+            mesh
+        );
+
+        let south_west = mesh.shift(-1, -1).unwrap();
+        assert_eq!(
+            JPMesh::new_with_options(min, JPMeshType::Mesh1km, opts),
+            south_west
+        );
+
+        let near_west = Coordinates::new(min.lng + 1e-10, min.lat + 0.001);
+        assert_eq!(
+            JPMesh::new_with_options(near_west, JPMeshType::Mesh1km, opts),
+            mesh.shift(-1, 0).unwrap()
+        );
+
+        let inside = mesh.to_bounds().center();
+        assert_eq!(
+            JPMesh::new_with_options(inside, JPMeshType::Mesh1km, opts),
+            mesh
+        );
+
+        // 範囲の端では調整しない
+        let origin = Coordinates::new(100.0, 0.0);
+        assert_eq!(
+            JPMesh::new_with_options(origin, JPMeshType::Mesh1km, opts),
+            JPMesh::from_grid_index(0, 0, JPMeshType::Mesh1km).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_125m_precision_at_high_latitude() {
+        // 境界は格子上の位置から秒単位で計算するため、範囲の北東端付近でも誤差は数ULP程度に収まる
+        const TIGHT_EPSILON: f64 = 1e-12;
+
+        let domain = crate::mesh_type::mesh_domain();
+        let north_east =
+            Coordinates::new(domain.max().lng.next_down(), domain.max().lat.next_down());
+        for coords in [
+            Coordinates::new(148.9, 45.55),
+            Coordinates::new(145.8, 45.5),
+            north_east,
+        ] {
+            let mesh = JPMesh::new(coords, JPMeshType::Mesh125m);
+            let bounds = mesh.to_bounds();
+            assert!(bounds.includes(coords));
+            assert!((bounds.max().lng - bounds.min().lng - 5.625 / 3600.0).abs() < TIGHT_EPSILON);
+            assert!((bounds.max().lat - bounds.min().lat - 3.75 / 3600.0).abs() < TIGHT_EPSILON);
+
+            // 隣接する地域メッシュと境界が一致する
+            if let Some(east) = mesh.walk_east(1).next() {
+                assert_eq!(east.to_bounds().min().lng, bounds.max().lng);
+            }
+            if let Some(north) = mesh.walk_north(1).next() {
+                assert_eq!(north.to_bounds().min().lat, bounds.max().lat);
+            }
+        }
+
+        let (cols, rows) = JPMeshType::Mesh125m.grid_size();
+        assert_eq!(
+            JPMesh::new(north_east, JPMeshType::Mesh125m).grid_index(),
+            (cols - 1, rows - 1)
+        );
+    }
+
+    #[test]
+    fn test_from_wkt_point() {
+        assert_eq!(
+            JPMesh::from_wkt_point("POINT (139.767125 35.681236)", JPMeshType::Mesh1km),
+            Ok(JPMesh::from_number(53394611, JPMeshType::Mesh1km))
+        );
+        assert!(JPMesh::from_wkt_point("POINT EMPTY", JPMeshType::Mesh1km).is_err());
+    }
+
+    #[test]
+    fn test_from_census_filename() {
+        assert_eq!(
+            JPMesh::from_census_filename("tblT001082H5339.txt"),
+            Some(JPMesh::from_number(5339, JPMeshType::Mesh80km))
+        );
+        assert_eq!(
+            JPMesh::from_census_filename("data/2020/tblT001140S53394611.csv"),
+            Some(JPMesh::from_number(53394611, JPMeshType::Mesh1km))
+        );
+        assert_eq!(
+            JPMesh::from_census_filename("533946.zip"),
+            Some(JPMesh::from_number(533946, JPMeshType::Mesh10km))
+        );
+        assert_eq!(JPMesh::from_census_filename("tblT001082H.txt"), None);
+        assert_eq!(JPMesh::from_census_filename("tblT001082H53.txt"), None);
+        assert_eq!(JPMesh::from_census_filename(""), None);
+    }
+
+    #[test]
+    fn test_bounds_approx_eq() {
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let reference = Rect::from_tuple((139.7625, 35.675, 139.775, 35.68333333));
+
+        assert!(mesh.bounds_approx_eq(&reference, 1e-7));
+        assert!(!mesh.bounds_approx_eq(&reference, 1e-9));
+        assert!(mesh.bounds_approx_eq(&mesh.to_bounds(), 0.0));
+
+        let nan = Rect::from_tuple((f64::NAN, 35.675, 139.775, 35.68333333));
+        assert!(!mesh.bounds_approx_eq(&nan, 1.0));
+    }
+
+    #[test]
+    fn test_from_on_bounds_matches_cell_count() {
+        let bounds = Rect::new(
+            Coordinates::new(139.70, 35.60),
+            Coordinates::new(139.80, 35.70),
+        );
+        for mesh_type in JPMeshType::all() {
+            let meshes = JPMesh::from_on_bounds(bounds, mesh_type);
+            assert_eq!(meshes.len() as u64, mesh_type.cell_count_over(&bounds));
+            assert!(
+                meshes
+                    .iter()
+                    .all(|mesh| mesh.to_bounds().intersection(&bounds).is_some())
+            );
+        }
+
+        // 北東端がメッシュの境界に一致する場合、その外側は含まない
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(
+            JPMesh::from_on_bounds(mesh.to_bounds(), JPMeshType::Mesh1km),
+            vec![mesh]
+        );
     }
 }
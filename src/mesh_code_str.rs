@@ -0,0 +1,47 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::JPMesh;
+
+/// 地域メッシュを、メッシュコードの文字列 (例: `"53394611"`) としてシリアライズするためのラッパー
+///
+/// 先頭の0を含む桁数をそのまま保持し、デシリアライズ時は桁数から地域メッシュの種類を推定します。
+/// ハイフン区切りの文字列 (例: `"5339-46-11"`) もデシリアライズできます。
+///
+/// # サンプル
+/// ```
+/// use rust_jp_mesh::{JPMesh, JPMeshType, MeshCodeStr};
+///
+/// let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+/// let json = serde_json::to_string(&MeshCodeStr(mesh)).unwrap();
+/// assert_eq!(json, "\"53394611\"");
+///
+/// let MeshCodeStr(decoded) = serde_json::from_str(&json).unwrap();
+/// assert_eq!(decoded, mesh);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MeshCodeStr(pub JPMesh);
+
+impl Serialize for MeshCodeStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeshCodeStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map(MeshCodeStr).map_err(de::Error::custom)
+    }
+}
+
+impl From<JPMesh> for MeshCodeStr {
+    fn from(mesh: JPMesh) -> Self {
+        MeshCodeStr(mesh)
+    }
+}
+
+impl From<MeshCodeStr> for JPMesh {
+    fn from(mesh: MeshCodeStr) -> Self {
+        mesh.0
+    }
+}
@@ -0,0 +1,36 @@
+/// 地域メッシュの種類とメッシュコードを1つの `u64` に詰めた識別子
+///
+/// ビットの配置は以下のとおりです。
+/// - 上位3ビット (61〜63): 地域メッシュの種類の識別番号 (0〜7)
+/// - 下位61ビット (0〜60): メッシュコード
+///
+/// 比較は `u64` の値で行うため、種類ごとにまとまり、同じ種類の中ではメッシュコードの昇順に並びます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MeshId(u64);
+
+impl MeshId {
+    const TAG_SHIFT: u32 = 61;
+    const CODE_MASK: u64 = (1 << Self::TAG_SHIFT) - 1;
+
+    pub(crate) fn new(tag: u8, code: u64) -> Self {
+        MeshId(((tag as u64) << Self::TAG_SHIFT) | (code & Self::CODE_MASK))
+    }
+
+    /// 識別子を `u64` の値から生成します。
+    pub fn from_raw(raw: u64) -> Self {
+        MeshId(raw)
+    }
+
+    /// 識別子の `u64` の値を取得します。
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+
+    pub(crate) fn tag(&self) -> u8 {
+        (self.0 >> Self::TAG_SHIFT) as u8
+    }
+
+    pub(crate) fn code(&self) -> u64 {
+        self.0 & Self::CODE_MASK
+    }
+}
@@ -0,0 +1,1108 @@
+//! 地域メッシュの集合を扱う関数群
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::io::{self, Read, Write};
+
+use serde_json::Value;
+
+use crate::{Coordinates, GeoJsonOptions, JPMesh, JPMeshError, JPMeshType, MeshId, Rect, geojson};
+
+/// 同じ種類の地域メッシュの集合
+///
+/// 種類の異なる地域メッシュを混在させることはできません。
+/// 種類の異なる地域メッシュを挿入したり、種類の異なる集合同士を演算した場合はパニックします。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeshSet {
+    mesh_type: JPMeshType,
+    meshes: HashSet<JPMesh>,
+}
+
+impl MeshSet {
+    /// 指定された種類の地域メッシュを格納する、空の集合を生成します。
+    pub fn new(mesh_type: JPMeshType) -> Self {
+        Self {
+            mesh_type,
+            meshes: HashSet::new(),
+        }
+    }
+
+    /// 集合に格納する地域メッシュの種類を取得します。
+    pub fn mesh_type(&self) -> JPMeshType {
+        self.mesh_type
+    }
+
+    /// 地域メッシュを集合に追加します。新たに追加された場合は `true` を返します。
+    ///
+    /// # Panics
+    /// 地域メッシュの種類が集合の種類と異なる場合はパニックします。
+    pub fn insert(&mut self, mesh: JPMesh) -> bool {
+        assert_eq!(
+            mesh.mesh_type(),
+            self.mesh_type,
+            "mesh type does not match the set"
+        );
+        self.meshes.insert(mesh)
+    }
+
+    /// 地域メッシュが集合に含まれるかどうかを判定します。
+    pub fn contains(&self, mesh: &JPMesh) -> bool {
+        self.meshes.contains(mesh)
+    }
+
+    /// 集合に含まれる地域メッシュの数を取得します。
+    pub fn len(&self) -> usize {
+        self.meshes.len()
+    }
+
+    /// 集合が空かどうかを判定します。
+    pub fn is_empty(&self) -> bool {
+        self.meshes.is_empty()
+    }
+
+    /// 集合に含まれる地域メッシュを順不同で返すイテレータを取得します。
+    pub fn iter(&self) -> impl Iterator<Item = &JPMesh> {
+        self.meshes.iter()
+    }
+
+    /// 2つの集合の和集合を取得します。
+    ///
+    /// # Panics
+    /// 集合の種類が異なる場合はパニックします。
+    pub fn union(&self, other: &MeshSet) -> MeshSet {
+        self.combine(other, self.meshes.union(&other.meshes))
+    }
+
+    /// 2つの集合の積集合を取得します。
+    ///
+    /// # Panics
+    /// 集合の種類が異なる場合はパニックします。
+    pub fn intersection(&self, other: &MeshSet) -> MeshSet {
+        self.combine(other, self.meshes.intersection(&other.meshes))
+    }
+
+    /// この集合から、もう一方の集合に含まれる地域メッシュを除いた差集合を取得します。
+    ///
+    /// # Panics
+    /// 集合の種類が異なる場合はパニックします。
+    pub fn difference(&self, other: &MeshSet) -> MeshSet {
+        self.combine(other, self.meshes.difference(&other.meshes))
+    }
+
+    /// 集合を GeoJSON の FeatureCollection に変換します。
+    ///
+    /// 各 Feature はメッシュコードを `mesh_code` プロパティに持ち、メッシュコードの昇順に並びます。
+    pub fn to_geojson(&self) -> Value {
+        self.to_geojson_with_options(&GeoJsonOptions::default())
+    }
+
+    /// 集合を、指定された設定で GeoJSON の FeatureCollection に変換します。
+    ///
+    /// `bbox` を有効にした場合、FeatureCollection の `bbox` にはすべての地域メッシュを含む範囲を出力します。
+    pub fn to_geojson_with_options(&self, options: &GeoJsonOptions) -> Value {
+        let mut meshes: Vec<&JPMesh> = self.meshes.iter().collect();
+        meshes.sort_by_key(|mesh| mesh.to_number());
+
+        let bbox = if options.bbox {
+            meshes
+                .iter()
+                .flat_map(|mesh| mesh.to_bounds().iter_corners())
+                .collect::<Option<Rect>>()
+        } else {
+            None
+        };
+        let features = meshes
+            .into_iter()
+            .map(|mesh| geojson::mesh_to_feature(mesh, options))
+            .collect();
+        geojson::feature_collection(features, bbox)
+    }
+
+    fn combine<'a>(&self, other: &MeshSet, meshes: impl Iterator<Item = &'a JPMesh>) -> MeshSet {
+        assert_eq!(
+            self.mesh_type, other.mesh_type,
+            "mesh types of the sets do not match"
+        );
+        MeshSet {
+            mesh_type: self.mesh_type,
+            meshes: meshes.copied().collect(),
+        }
+    }
+}
+
+impl Extend<JPMesh> for MeshSet {
+    fn extend<I: IntoIterator<Item = JPMesh>>(&mut self, iter: I) {
+        for mesh in iter {
+            self.insert(mesh);
+        }
+    }
+}
+
+/// 地域メッシュごとの件数を、指定された種類の地域メッシュごとに集計します。
+///
+/// 各地域メッシュの件数は、それを含む指定された種類の地域メッシュ ([`JPMesh::parent`]) に加算します。
+/// 指定された種類と同じ種類の地域メッシュはそのまま加算し、
+/// 指定された種類の地域メッシュに含まれない (より大きい、または隙間なく含まれない) 地域メッシュは無視します。
+pub fn aggregate_counts(counts: &HashMap<JPMesh, u64>, target: JPMeshType) -> HashMap<JPMesh, u64> {
+    let mut aggregated = HashMap::new();
+    for (mesh, &count) in counts {
+        let parent = if mesh.mesh_type() == target {
+            Some(*mesh)
+        } else {
+            mesh.parent(target)
+        };
+        if let Some(parent) = parent {
+            *aggregated.entry(parent).or_insert(0) += count;
+        }
+    }
+    aggregated
+}
+
+/// 親の地域メッシュに含まれる地域メッシュがすべて揃っている場合に、それらを親の地域メッシュ1つに置き換えます。
+///
+/// 細かい種類から順に、`up_to` の種類までこの置き換えを繰り返し、種類の混在した地域メッシュの列を取得します。
+/// 親子の階層は第1次地域区画から8分の1地域メッシュまでの標準的な階層とし、
+/// `up_to` が2倍・5倍地域メッシュの場合は、基準地域メッシュの親をその種類とします。
+/// 他の地域メッシュに含まれる地域メッシュは除かれます。階層に含まれない種類の地域メッシュは、そのまま残ります。
+/// 結果は重複を含まず、大きい種類から順に、同じ種類の中ではメッシュコードの昇順に並びます。
+pub fn coalesce(codes: &[JPMesh], up_to: JPMeshType) -> Vec<JPMesh> {
+    let levels: Vec<JPMeshType> = JPMeshType::all()
+        .into_iter()
+        .rev()
+        .filter(|&level| level <= up_to && (level.is_standard() || level == up_to))
+        .collect();
+
+    let mut set: HashSet<JPMesh> = codes.iter().copied().collect();
+    for pair in levels.windows(2) {
+        let (child, parent) = (pair[0], pair[1]);
+        let Some((lng_factor, lat_factor)) = parent.subdivision_factor(child) else {
+            continue;
+        };
+
+        let mut counts: HashMap<JPMesh, u64> = HashMap::new();
+        for mesh in set.iter().filter(|mesh| mesh.mesh_type() == child) {
+            if let Some(parent) = mesh.parent(parent) {
+                *counts.entry(parent).or_insert(0) += 1;
+            }
+        }
+        let complete: HashSet<JPMesh> = counts
+            .into_iter()
+            .filter(|&(_, count)| count == lng_factor * lat_factor)
+            .map(|(parent, _)| parent)
+            .collect();
+
+        set.retain(|mesh| {
+            mesh.mesh_type() != child
+                || !mesh
+                    .parent(parent)
+                    .is_some_and(|parent| complete.contains(&parent))
+        });
+        set.extend(complete);
+    }
+
+    let covered: Vec<JPMesh> = set
+        .iter()
+        .copied()
+        .filter(|mesh| {
+            levels
+                .iter()
+                .filter(|&&level| level > mesh.mesh_type())
+                .filter_map(|&level| mesh.parent(level))
+                .any(|ancestor| set.contains(&ancestor))
+        })
+        .collect();
+    for mesh in covered {
+        set.remove(&mesh);
+    }
+
+    let mut coalesced: Vec<JPMesh> = set.into_iter().collect();
+    coalesced.sort_by_key(|mesh| (Reverse(mesh.mesh_type()), mesh.to_number()));
+    coalesced
+}
+
+/// 地域メッシュの分布を、端末で確認するための文字の地図として描画します。
+///
+/// 地域メッシュ全体を含む格子上の範囲を、幅 `width` 文字に縮小または拡大し、地域メッシュを含む位置を `#`、含まない位置を `.` で表します。
+/// 端末の文字は縦が横のおよそ2倍であるため、1文字が表す範囲の南北の長さが東西の長さの2倍となるよう行数を決めます。
+/// 北を上とし、各行は改行で終わります。地域メッシュがない場合や `width` が0の場合は空の文字列を返します。
+///
+/// # Panics
+/// 地域メッシュの種類が揃っていない場合はパニックします。
+pub fn render_ascii(codes: &[JPMesh], width: usize) -> String {
+    assert_same_type(codes);
+    let Some(first) = codes.first() else {
+        return String::new();
+    };
+    if width == 0 {
+        return String::new();
+    }
+
+    let indices: Vec<(u64, u64)> = codes.iter().map(|mesh| mesh.grid_index()).collect();
+    let col0 = indices.iter().map(|&(col, _)| col).min().unwrap();
+    let col1 = indices.iter().map(|&(col, _)| col).max().unwrap();
+    let row0 = indices.iter().map(|&(_, row)| row).min().unwrap();
+    let row1 = indices.iter().map(|&(_, row)| row).max().unwrap();
+    let (cols, rows) = ((col1 - col0 + 1) as f64, (row1 - row0 + 1) as f64);
+
+    // 1文字あたりの区画数 (経度方向, 緯度方向)
+    let lng_cells = cols / width as f64;
+    let lat_cells = lng_cells * 2.0 * first.cell_width_meters() / first.cell_height_meters();
+    let height = ((rows / lat_cells).ceil() as usize).max(1);
+
+    // 区画 [start, start + 1) が重なる文字の位置の範囲
+    let span = |start: u64, cells_per_char: f64, count: usize| {
+        let first = ((start as f64 / cells_per_char).floor() as usize).min(count - 1);
+        let last = (((start + 1) as f64 / cells_per_char).ceil() as usize).saturating_sub(1);
+        first..=last.clamp(first, count - 1)
+    };
+
+    let mut grid = vec![vec![false; width]; height];
+    for (col, row) in indices {
+        for y in span(row1 - row, lat_cells, height) {
+            for x in span(col - col0, lng_cells, width) {
+                grid[y][x] = true;
+            }
+        }
+    }
+
+    grid.into_iter()
+        .map(|line| {
+            let mut line: String = line
+                .into_iter()
+                .map(|occupied| if occupied { '#' } else { '.' })
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+/// GeoJSON の FeatureCollection (または単一の Feature) から地域メッシュを読み取ります。
+///
+/// 各 Feature の `mesh_code` プロパティ (数値または文字列) をメッシュコードとして解釈し、種類は桁数から判定します。
+/// `mesh_code` プロパティがない Feature は、Polygon の外周の範囲の大きさから種類を判定し、その中心を含む地域メッシュとします。
+/// 地域メッシュは Feature の順に並びます。
+pub fn from_geojson(value: &Value) -> Result<Vec<JPMesh>, JPMeshError> {
+    match value["type"].as_str() {
+        Some("FeatureCollection") => value["features"]
+            .as_array()
+            .ok_or_else(|| {
+                JPMeshError::InvalidGeoJson("features が配列ではありません".to_string())
+            })?
+            .iter()
+            .map(geojson::feature_to_mesh)
+            .collect(),
+        Some("Feature") => Ok(vec![geojson::feature_to_mesh(value)?]),
+        _ => Err(JPMeshError::InvalidGeoJson(
+            "Feature または FeatureCollection ではありません".to_string(),
+        )),
+    }
+}
+
+/// 地域メッシュ全体を含む最小の矩形を取得します。([`Rect::extend_to_cell`] による各地域メッシュの範囲の和)
+///
+/// 種類の異なる地域メッシュが混在していても構いません。地域メッシュがない場合は `None` を返します。
+pub fn bounding_rect(codes: &[JPMesh]) -> Option<Rect> {
+    let (first, rest) = codes.split_first()?;
+    Some(
+        rest.iter()
+            .fold(first.to_bounds(), |rect, mesh| rect.extend_to_cell(mesh)),
+    )
+}
+
+/// 地域メッシュ全体を含み、辺が `mesh_type` の格子線に揃った最小の矩形を取得します。
+///
+/// [`bounding_rect`] を、`mesh_type` の地域メッシュの範囲まで広げたものです。
+/// 地域メッシュがない場合や、メッシュコードで表現できる範囲の外にある場合は `None` を返します。
+pub fn tight_mesh_aligned_rect(codes: &[JPMesh], mesh_type: JPMeshType) -> Option<Rect> {
+    let ((min_col, min_row), (max_col, max_row)) =
+        bounding_rect(codes)?.grid_index_range(mesh_type)?;
+    Some(Rect::new(
+        mesh_type.grid_cell_bounds(min_col, min_row).min(),
+        mesh_type.grid_cell_bounds(max_col, max_row).max(),
+    ))
+}
+
+/// 地域メッシュ全体を含む格子上の範囲の、南西端と北東端の地域メッシュを取得します。
+///
+/// 列番号・行番号それぞれの最小値・最大値から求めるため、返す地域メッシュが `codes` に含まれるとは限りません。
+/// 地域メッシュがない場合は `None` を返します。
+///
+/// # Panics
+/// 地域メッシュの種類が揃っていない場合はパニックします。
+pub fn extent_cells(codes: &[JPMesh]) -> Option<(JPMesh, JPMesh)> {
+    assert_same_type(codes);
+    let mesh_type = codes.first()?.mesh_type();
+
+    let (min, max) = codes.iter().map(|mesh| mesh.grid_index()).fold(
+        ((u64::MAX, u64::MAX), (0, 0)),
+        |(min, max), (col, row)| {
+            (
+                (min.0.min(col), min.1.min(row)),
+                (max.0.max(col), max.1.max(row)),
+            )
+        },
+    );
+    let corner = |(col, row)| {
+        JPMesh::from_grid_index(col, row, mesh_type)
+            .expect("grid index within the extent of defined meshes should be defined")
+    };
+    Some((corner(min), corner(max)))
+}
+
+/// 各地域メッシュを、格子上で `k` 区画ずつ広げた地域メッシュを取得します。([`JPMesh::disk`] の和集合)
+///
+/// 結果は重複を含まず、メッシュコードの昇順に並びます。
+///
+/// # Panics
+/// 地域メッシュの種類が揃っていない場合はパニックします。
+pub fn dilate(codes: &[JPMesh], k: u32) -> Vec<JPMesh> {
+    assert_same_type(codes);
+
+    let dilated: HashSet<JPMesh> = codes.iter().flat_map(|mesh| mesh.disk(k)).collect();
+    sorted_by_code(dilated)
+}
+
+/// 格子上で `k` 区画以内の地域メッシュ ([`JPMesh::disk`]) がすべて含まれる地域メッシュだけを取得します。
+///
+/// [`dilate`] と対になる処理です。メッシュコードで表現できる範囲の外は含まれないものとして扱います。
+/// 結果は重複を含まず、メッシュコードの昇順に並びます。
+///
+/// # Panics
+/// 地域メッシュの種類が揃っていない場合はパニックします。
+pub fn erode(codes: &[JPMesh], k: u32) -> Vec<JPMesh> {
+    assert_same_type(codes);
+
+    let set: HashSet<JPMesh> = codes.iter().copied().collect();
+    let side = 2 * k as usize + 1;
+    let eroded = set.iter().copied().filter(|mesh| {
+        let disk = mesh.disk(k);
+        disk.len() == side * side && disk.iter().all(|neighbor| set.contains(neighbor))
+    });
+    sorted_by_code(eroded)
+}
+
+/// 隣接する地域メッシュを結合し、結合した領域の境界を閉じた頂点列として取得します。
+///
+/// 隣接する地域メッシュが共有する辺を打ち消し、残った辺をたどって境界を求めます。
+/// 外周は反時計回り、穴の境界は時計回りの頂点列となり、同一直線上の頂点は除かれます。
+/// 頂点のみで接する地域メッシュは、別の境界として出力します。
+///
+/// # Panics
+/// 地域メッシュの種類が揃っていない場合はパニックします。
+pub fn dissolve(codes: &[JPMesh]) -> Vec<Vec<Coordinates>> {
+    assert_same_type(codes);
+    let Some(mesh_type) = codes.first().map(|mesh| mesh.mesh_type()) else {
+        return Vec::new();
+    };
+
+    // 各地域メッシュの辺を反時計回りの向きで登録し、逆向きの辺と打ち消し合わせる
+    let mut edges: HashSet<((i64, i64), (i64, i64))> = HashSet::new();
+    let cells: HashSet<(i64, i64)> = codes
+        .iter()
+        .map(|mesh| {
+            let (col, row) = mesh.grid_index();
+            (col as i64, row as i64)
+        })
+        .collect();
+    for &(col, row) in &cells {
+        let corners = [
+            (col, row),
+            (col + 1, row),
+            (col + 1, row + 1),
+            (col, row + 1),
+        ];
+        for i in 0..4 {
+            let edge = (corners[i], corners[(i + 1) % 4]);
+            if !edges.remove(&(edge.1, edge.0)) {
+                edges.insert(edge);
+            }
+        }
+    }
+
+    let mut outgoing: BTreeMap<(i64, i64), Vec<(i64, i64)>> = BTreeMap::new();
+    for (from, to) in edges {
+        outgoing.entry(from).or_default().push(to);
+    }
+
+    let mut rings = Vec::new();
+    while let Some(mut entry) = outgoing.first_entry() {
+        let start = *entry.key();
+        let targets = entry.get_mut();
+        targets.sort();
+        let mut current = targets.remove(0);
+        if targets.is_empty() {
+            entry.remove();
+        }
+
+        let mut ring = vec![start];
+        while current != start {
+            let last = *ring.last().unwrap();
+            let direction = (current.0 - last.0, current.1 - last.1);
+            ring.push(current);
+
+            // 頂点のみで接する地域メッシュを分けるため、左折・直進・右折の順に次の辺を選ぶ
+            let targets = outgoing
+                .get_mut(&current)
+                .expect("boundary edges should form closed rings");
+            let next = [
+                (-direction.1, direction.0),
+                direction,
+                (direction.1, -direction.0),
+            ]
+            .into_iter()
+            .map(|(dcol, drow)| (current.0 + dcol, current.1 + drow))
+            .find(|next| targets.contains(next))
+            .expect("boundary edges should form closed rings");
+
+            targets.retain(|target| *target != next);
+            if targets.is_empty() {
+                outgoing.remove(&current);
+            }
+            current = next;
+        }
+
+        rings.push(
+            remove_collinear(&ring)
+                .into_iter()
+                .map(|(col, row)| mesh_type.grid_cell_bounds(col as u64, row as u64).min())
+                .collect(),
+        );
+    }
+
+    rings
+}
+
+/// 地域メッシュの辺を、隣接する地域メッシュ間で重複しない線分として取得します。
+///
+/// [`dissolve`] と異なり、隣接する地域メッシュが共有する内側の辺も1本の線分として残します。
+/// 塗りつぶさずに格子線だけを描画する用途を想定しています。
+/// 線分の端点は西・南側の頂点を始点とし、線分は始点の格子上の位置の順に並びます。
+///
+/// # Panics
+/// 地域メッシュの種類が揃っていない場合はパニックします。
+pub fn grid_lines(codes: &[JPMesh]) -> Vec<(Coordinates, Coordinates)> {
+    assert_same_type(codes);
+    let Some(mesh_type) = codes.first().map(|mesh| mesh.mesh_type()) else {
+        return Vec::new();
+    };
+
+    let mut edges: BTreeSet<((u64, u64), (u64, u64))> = BTreeSet::new();
+    for mesh in codes {
+        let (col, row) = mesh.grid_index();
+        edges.insert(((col, row), (col + 1, row)));
+        edges.insert(((col, row + 1), (col + 1, row + 1)));
+        edges.insert(((col, row), (col, row + 1)));
+        edges.insert(((col + 1, row), (col + 1, row + 1)));
+    }
+
+    let corner = |(col, row): (u64, u64)| mesh_type.grid_cell_bounds(col, row).min();
+    edges
+        .into_iter()
+        .map(|(from, to)| (corner(from), corner(to)))
+        .collect()
+}
+
+/// 閉じた頂点列 (始点を末尾に含まない) から同一直線上の頂点を除き、始点を末尾に加えた頂点列を取得します。
+fn remove_collinear(ring: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let n = ring.len();
+    let mut vertices: Vec<(i64, i64)> = (0..n)
+        .filter(|&i| {
+            let prev = ring[(i + n - 1) % n];
+            let current = ring[i];
+            let next = ring[(i + 1) % n];
+            (current.0 - prev.0, current.1 - prev.1) != (next.0 - current.0, next.1 - current.1)
+        })
+        .map(|i| ring[i])
+        .collect();
+    vertices.push(vertices[0]);
+    vertices
+}
+
+fn assert_same_type(codes: &[JPMesh]) {
+    if let Some(first) = codes.first() {
+        assert!(
+            codes
+                .iter()
+                .all(|mesh| mesh.mesh_type() == first.mesh_type()),
+            "mesh types of the codes do not match"
+        );
+    }
+}
+
+fn sorted_by_code(meshes: impl IntoIterator<Item = JPMesh>) -> Vec<JPMesh> {
+    let mut meshes: Vec<JPMesh> = meshes.into_iter().collect();
+    meshes.sort_by_key(|mesh| mesh.to_number());
+    meshes
+}
+
+/// 地域メッシュの列をバイナリ形式で書き出します。
+///
+/// 形式は以下のとおりです。整数はすべて可変長 (LEB128) で符号化します。
+/// - 先頭: 地域メッシュの数
+/// - 各要素: メッシュの種類の識別番号 (1バイト), 直前の要素とのメッシュコードの差 (ジグザグ符号化)
+///
+/// メッシュコードを整列させておくと差が小さくなり、出力が短くなります。
+pub fn write_binary<W: Write>(codes: &[JPMesh], w: &mut W) -> io::Result<()> {
+    write_varint(w, codes.len() as u64)?;
+
+    let mut prev = 0u64;
+    for mesh in codes {
+        let number = mesh.to_number();
+        let delta = number.wrapping_sub(prev) as i64;
+
+        w.write_all(&[mesh.mesh_type().tag()])?;
+        write_varint(w, zigzag_encode(delta))?;
+        prev = number;
+    }
+
+    Ok(())
+}
+
+/// [`write_binary`] で書き出された地域メッシュの列を読み込みます。
+pub fn read_binary<R: Read>(r: &mut R) -> io::Result<Vec<JPMesh>> {
+    let len = read_varint(r)?;
+
+    let mut codes = Vec::new();
+    let mut prev = 0u64;
+    for _ in 0..len {
+        let mut tag = [0u8; 1];
+        r.read_exact(&mut tag)?;
+        let mesh_type = JPMeshType::from_tag(tag[0]).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unknown mesh type tag: {}", tag[0]),
+            )
+        })?;
+
+        let delta = zigzag_decode(read_varint(r)?);
+        let number = prev.wrapping_add(delta as u64);
+
+        let mesh = JPMesh::from_id(MeshId::new(mesh_type.tag(), number)).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid mesh code for {:?}: {}", mesh_type, number),
+            )
+        })?;
+        codes.push(mesh);
+        prev = number;
+    }
+
+    Ok(codes)
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn write_varint<W: Write>(w: &mut W, mut value: u64) -> io::Result<()> {
+    while value >= 0x80 {
+        w.write_all(&[(value as u8 & 0x7f) | 0x80])?;
+        value >>= 7;
+    }
+    w.write_all(&[value as u8])
+}
+
+fn read_varint<R: Read>(r: &mut R) -> io::Result<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let mut byte = [0u8; 1];
+        r.read_exact(&mut byte)?;
+
+        if shift >= 64 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "varint is too long",
+            ));
+        }
+        // 10バイト目は最下位ビットのみが u64 に収まる
+        if shift == 63 && byte[0] & 0x7e != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "varint overflows u64",
+            ));
+        }
+        value |= ((byte[0] & 0x7f) as u64) << shift;
+
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mesh_set_of(numbers: &[u64]) -> MeshSet {
+        let mut set = MeshSet::new(JPMeshType::Mesh1km);
+        set.extend(
+            numbers
+                .iter()
+                .map(|&n| JPMesh::from_number(n, JPMeshType::Mesh1km)),
+        );
+        set
+    }
+
+    #[test]
+    fn test_mesh_set_operations() {
+        let a = mesh_set_of(&[53394611, 53394612, 53394621]);
+        let b = mesh_set_of(&[53394612, 53394621, 53394622]);
+
+        assert_eq!(
+            a.union(&b),
+            mesh_set_of(&[53394611, 53394612, 53394621, 53394622])
+        );
+        assert_eq!(a.intersection(&b), mesh_set_of(&[53394612, 53394621]));
+        assert_eq!(a.difference(&b), mesh_set_of(&[53394611]));
+
+        assert!(a.contains(&JPMesh::from_number(53394611, JPMeshType::Mesh1km)));
+        assert!(!b.contains(&JPMesh::from_number(53394611, JPMeshType::Mesh1km)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mesh_set_insert_other_type() {
+        let mut set = MeshSet::new(JPMeshType::Mesh1km);
+        set.insert(JPMesh::from_number(533946, JPMeshType::Mesh10km));
+    }
+
+    #[test]
+    fn test_mesh_set_to_geojson() {
+        let set = mesh_set_of(&[53394612, 53394611]);
+        let geojson = set.to_geojson();
+
+        assert_eq!(geojson["type"], "FeatureCollection");
+        let features = geojson["features"].as_array().unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[0]["properties"]["mesh_code"], 53394611);
+        assert_eq!(features[1]["properties"]["mesh_code"], 53394612);
+        assert_eq!(features[0]["geometry"]["type"], "Polygon");
+        assert_eq!(
+            features[0]["geometry"]["coordinates"][0]
+                .as_array()
+                .unwrap()
+                .len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_mesh_set_to_geojson_bbox() {
+        let set = mesh_set_of(&[53394612, 53394611]);
+
+        let geojson = set.to_geojson();
+        assert!(geojson.get("bbox").is_none());
+        assert!(geojson["features"][0].get("bbox").is_none());
+
+        let geojson = set.to_geojson_with_options(&GeoJsonOptions { bbox: true });
+        let first = JPMesh::from_number(53394611, JPMeshType::Mesh1km).to_bounds();
+        let second = JPMesh::from_number(53394612, JPMeshType::Mesh1km).to_bounds();
+        assert_eq!(
+            geojson["features"][0]["bbox"],
+            serde_json::json!([
+                first.min().lng,
+                first.min().lat,
+                first.max().lng,
+                first.max().lat
+            ])
+        );
+        assert_eq!(
+            geojson["bbox"],
+            serde_json::json!([
+                first.min().lng,
+                first.min().lat,
+                second.max().lng,
+                second.max().lat
+            ])
+        );
+    }
+
+    #[test]
+    fn test_dilate() {
+        let center = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let dilated = dilate(&[center], 1);
+        assert_eq!(dilated, sorted_by_code(center.disk(1)));
+        assert_eq!(dilated.len(), 9);
+
+        // 隣接する2つの地域メッシュの和集合は 4×3 区画
+        let east = center.walk_east(1).next().unwrap();
+        assert_eq!(dilate(&[center, east], 1).len(), 12);
+
+        assert!(dilate(&[], 1).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dilate_other_type() {
+        dilate(
+            &[
+                JPMesh::from_number(53394611, JPMeshType::Mesh1km),
+                JPMesh::from_number(533946, JPMeshType::Mesh10km),
+            ],
+            1,
+        );
+    }
+
+    #[test]
+    fn test_erode() {
+        let center = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let square = center.disk(2);
+
+        let eroded = erode(&square, 1);
+        assert_eq!(eroded, sorted_by_code(center.disk(1)));
+        assert_eq!(erode(&square, 2), vec![center]);
+        assert!(erode(&square, 3).is_empty());
+
+        // 範囲の端の地域メッシュは残らない
+        let origin = JPMesh::from_grid_index(0, 0, JPMeshType::Mesh1km).unwrap();
+        assert!(!erode(&origin.disk(2), 1).contains(&origin));
+    }
+
+    /// 頂点列の符号付き面積 (反時計回りで正)
+    fn signed_area(ring: &[Coordinates]) -> f64 {
+        ring.windows(2)
+            .map(|w| w[0].lng * w[1].lat - w[1].lng * w[0].lat)
+            .sum::<f64>()
+            / 2.0
+    }
+
+    #[test]
+    fn test_dissolve_block() {
+        let center = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let block = center.disk(1);
+
+        let rings = dissolve(&block);
+        assert_eq!(rings.len(), 1);
+        assert_eq!(rings[0].len(), 5);
+        assert_eq!(rings[0].first(), rings[0].last());
+        assert!(signed_area(&rings[0]) > 0.0);
+
+        let extent = block
+            .iter()
+            .flat_map(|mesh| mesh.to_bounds().iter_corners())
+            .collect::<Option<Rect>>()
+            .unwrap();
+        let ring_extent = rings[0].iter().copied().collect::<Option<Rect>>().unwrap();
+        assert_eq!(ring_extent, extent);
+
+        assert!(dissolve(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_dissolve_hole_and_diagonal() {
+        let center = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+
+        // 中央を除いた 3×3 区画は、外周と穴の2つの境界になる
+        let frame: Vec<JPMesh> = center
+            .disk(1)
+            .into_iter()
+            .filter(|mesh| *mesh != center)
+            .collect();
+        let rings = dissolve(&frame);
+        assert_eq!(rings.len(), 2);
+        assert_eq!(
+            rings.iter().filter(|ring| signed_area(ring) > 0.0).count(),
+            1
+        );
+        assert_eq!(
+            rings.iter().filter(|ring| signed_area(ring) < 0.0).count(),
+            1
+        );
+
+        // 頂点のみで接する地域メッシュは別の境界になる
+        let diagonal = [
+            center,
+            center
+                .walk_east(1)
+                .next()
+                .unwrap()
+                .walk_north(1)
+                .next()
+                .unwrap(),
+        ];
+        let rings = dissolve(&diagonal);
+        assert_eq!(rings.len(), 2);
+        assert!(rings.iter().all(|ring| ring.len() == 5));
+    }
+
+    #[test]
+    fn test_from_geojson() {
+        let set = mesh_set_of(&[53394612, 53394611, 53394621]);
+        let meshes = from_geojson(&set.to_geojson()).unwrap();
+        assert_eq!(meshes, sorted_by_code(set.iter().copied()));
+
+        // mesh_code プロパティがない場合は Polygon から求める
+        let mut geojson = set.to_geojson();
+        for feature in geojson["features"].as_array_mut().unwrap() {
+            feature["properties"] = serde_json::json!({});
+        }
+        assert_eq!(from_geojson(&geojson).unwrap(), meshes);
+
+        let mesh = JPMesh::from_number(533946405, JPMeshType::Mesh2km);
+        let feature = mesh
+            .to_geojson_feature_with(&serde_json::json!({}))
+            .unwrap();
+        assert_eq!(from_geojson(&feature).unwrap(), vec![mesh]);
+
+        assert!(from_geojson(&serde_json::json!({"type": "Point"})).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_counts() {
+        let parent = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let other = JPMesh::from_number(53394612, JPMeshType::Mesh1km);
+
+        let mut counts: HashMap<JPMesh, u64> = parent
+            .iter_children(JPMeshType::Mesh125m)
+            .map(|mesh| (mesh, 2))
+            .collect();
+        counts.insert(other.children(JPMeshType::Mesh125m)[0], 5);
+        counts.insert(other, 1);
+        counts.insert(JPMesh::from_number(533946, JPMeshType::Mesh10km), 100);
+
+        let aggregated = aggregate_counts(&counts, JPMeshType::Mesh1km);
+        assert_eq!(aggregated.len(), 2);
+        assert_eq!(aggregated[&parent], 64 * 2);
+        assert_eq!(aggregated[&other], 6);
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let parent = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let children = parent.children(JPMeshType::Mesh125m);
+        assert_eq!(coalesce(&children, JPMeshType::Mesh1km), vec![parent]);
+        assert_eq!(coalesce(&children, JPMeshType::Mesh80km), vec![parent]);
+
+        // 揃っていない部分は細かい種類のまま残る
+        let partial = &children[1..];
+        let coalesced = coalesce(partial, JPMeshType::Mesh1km);
+        assert_eq!(coalesced.len(), 3 + 3 + 3);
+        assert_eq!(
+            coalesced
+                .iter()
+                .filter(|mesh| mesh.mesh_type() == JPMeshType::Mesh500m)
+                .count(),
+            3
+        );
+        assert!(
+            coalesced
+                .iter()
+                .all(|mesh| mesh.parent(JPMeshType::Mesh1km) == Some(parent))
+        );
+
+        // up_to より上には置き換えない
+        let coalesced = coalesce(&children, JPMeshType::Mesh500m);
+        assert_eq!(coalesced, parent.children(JPMeshType::Mesh500m));
+
+        // 他の地域メッシュに含まれる地域メッシュは除かれる
+        let mut codes = vec![parent, children[0]];
+        codes.extend(parent.walk_east(1));
+        let coalesced = coalesce(&codes, JPMeshType::Mesh1km);
+        assert_eq!(coalesced.len(), 2);
+        assert!(
+            coalesced
+                .iter()
+                .all(|mesh| mesh.mesh_type() == JPMeshType::Mesh1km)
+        );
+
+        // 5倍地域メッシュまでの置き換え
+        let mesh5km = JPMesh::from_number(5339461, JPMeshType::Mesh5km);
+        assert_eq!(
+            coalesce(&mesh5km.children(JPMeshType::Mesh1km), JPMeshType::Mesh5km),
+            vec![mesh5km]
+        );
+    }
+
+    #[test]
+    fn test_render_ascii() {
+        let origin = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let (col, row) = origin.grid_index();
+        let at = |dcol: u64, drow: u64| {
+            JPMesh::from_grid_index(col + dcol, row + drow, JPMeshType::Mesh1km).unwrap()
+        };
+        assert_eq!(render_ascii(&[], 10), "");
+        assert_eq!(render_ascii(&[origin], 0), "");
+        assert_eq!(render_ascii(&[origin], 1), "#\n");
+
+        // 東西に並ぶ地域メッシュ (1区画分の隙間あり)
+        let row: Vec<JPMesh> = std::iter::once(origin)
+            .chain(origin.walk_east(7))
+            .filter(|mesh| *mesh != at(3, 0))
+            .collect();
+        assert_eq!(render_ascii(&row, 8), "###.####\n");
+        assert_eq!(render_ascii(&row, 4), "####\n");
+        assert_eq!(render_ascii(&row[..2], 4), "####\n");
+
+        // 南北に並ぶ地域メッシュは、1文字が約2.4区画 (1km メッシュ, 北緯35度付近) を表す
+        let column: Vec<JPMesh> = std::iter::once(origin)
+            .chain(origin.walk_north(9))
+            .collect();
+        assert_eq!(render_ascii(&column, 1), "#\n".repeat(5));
+
+        // 北が上となる
+        let corner = [origin, at(5, 5)];
+        let map = render_ascii(&corner, 6);
+        let lines: Vec<&str> = map.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], ".....#");
+        assert_eq!(lines[2], "#.....");
+    }
+
+    #[test]
+    fn test_bounding_rect() {
+        assert_eq!(bounding_rect(&[]), None);
+
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(bounding_rect(&[mesh]), Some(mesh.to_bounds()));
+
+        // 種類の混在した地域メッシュ
+        let fine = JPMesh::from_number(53394622444, JPMeshType::Mesh125m);
+        let coarse = JPMesh::from_number(533945, JPMeshType::Mesh10km);
+        let rect = bounding_rect(&[fine, mesh, coarse]).unwrap();
+        assert_eq!(rect.min(), coarse.to_bounds().min());
+        assert_eq!(rect.max().lng, fine.to_bounds().max().lng);
+        assert_eq!(rect.max().lat, coarse.to_bounds().max().lat);
+    }
+
+    #[test]
+    fn test_grid_lines() {
+        assert!(grid_lines(&[]).is_empty());
+
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let lines = grid_lines(&[mesh]);
+        assert_eq!(lines.len(), 4);
+        let bounds = mesh.to_bounds();
+        assert!(lines.iter().all(|(from, to)| {
+            bounds.iter_corners().any(|c| c == *from) && bounds.iter_corners().any(|c| c == *to)
+        }));
+
+        // 2×1 区画では共有する辺を1本として数える
+        let east = mesh.shift(1, 0).unwrap();
+        let lines = grid_lines(&[mesh, east, mesh]);
+        assert_eq!(lines.len(), 7);
+        let shared = (east.to_bounds().min(), mesh.to_bounds().max());
+        assert_eq!(lines.iter().filter(|line| **line == shared).count(), 1);
+        assert!(
+            lines
+                .iter()
+                .all(|(from, to)| from.lng <= to.lng && from.lat <= to.lat)
+        );
+
+        // 3×3 区画は縦横4本ずつの格子線が3区画ずつに分かれる
+        assert_eq!(grid_lines(&mesh.disk(1)).len(), 24);
+    }
+
+    #[test]
+    fn test_extent_cells() {
+        assert_eq!(extent_cells(&[]), None);
+
+        let origin = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let (col, row) = origin.grid_index();
+        let at = |dcol: u64, drow: u64| {
+            JPMesh::from_grid_index(col + dcol, row + drow, JPMeshType::Mesh1km).unwrap()
+        };
+        assert_eq!(extent_cells(&[origin]), Some((origin, origin)));
+
+        // 南西端・北東端の地域メッシュは含まれていなくてもよい
+        let codes = [at(0, 2), at(3, 0), at(1, 1)];
+        assert_eq!(extent_cells(&codes), Some((at(0, 0), at(3, 2))));
+    }
+
+    #[test]
+    fn test_tight_mesh_aligned_rect() {
+        assert_eq!(tight_mesh_aligned_rect(&[], JPMeshType::Mesh1km), None);
+
+        let fine = JPMesh::from_number(53394611444, JPMeshType::Mesh125m);
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(
+            tight_mesh_aligned_rect(&[fine], JPMeshType::Mesh1km),
+            Some(mesh.to_bounds())
+        );
+        assert_eq!(
+            tight_mesh_aligned_rect(&[fine], JPMeshType::Mesh125m),
+            Some(fine.to_bounds())
+        );
+
+        // 格子線上の辺は隣の地域メッシュまで広げない
+        let east = mesh.shift(1, 0).unwrap();
+        let rect = tight_mesh_aligned_rect(&[mesh, east], JPMeshType::Mesh500m).unwrap();
+        assert_eq!(rect, bounding_rect(&[mesh, east]).unwrap());
+
+        let primary = JPMesh::from_number(5339, JPMeshType::Mesh80km);
+        assert_eq!(
+            tight_mesh_aligned_rect(&[fine, mesh], JPMeshType::Mesh80km),
+            Some(primary.to_bounds())
+        );
+    }
+
+    #[test]
+    fn test_varint() {
+        for value in [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX - 1, u64::MAX] {
+            let mut buf = Vec::new();
+            write_varint(&mut buf, value).unwrap();
+            assert_eq!(read_varint(&mut buf.as_slice()).unwrap(), value);
+        }
+
+        // u64::MAX の10バイト目 (0x01) より大きい値は u64 に収まらない
+        let mut overflow = vec![0xff; 9];
+        overflow.push(0x02);
+        assert_eq!(
+            read_varint(&mut overflow.as_slice()).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        let mut too_long = vec![0x80; 10];
+        too_long.push(0x00);
+        assert_eq!(
+            read_varint(&mut too_long.as_slice()).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let parent = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        let mut codes = parent.children(JPMeshType::Mesh125m);
+        codes.push(JPMesh::from_number(5339, JPMeshType::Mesh80km));
+        codes.push(JPMesh::from_number(533946, JPMeshType::Mesh10km));
+        codes.push(JPMesh::from_number(533946405, JPMeshType::Mesh2km));
+        codes.push(JPMesh::from_number(5339461, JPMeshType::Mesh5km));
+
+        let mut buf = Vec::new();
+        write_binary(&codes, &mut buf).unwrap();
+
+        let decoded = read_binary(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, codes);
+    }
+
+    #[test]
+    fn test_binary_sorted_is_compact() {
+        let parent = JPMesh::from_number(5339, JPMeshType::Mesh80km);
+        let codes = parent.children(JPMeshType::Mesh1km);
+
+        let mut buf = Vec::new();
+        write_binary(&codes, &mut buf).unwrap();
+
+        // 1要素あたり平均で 1 + 8 バイト (識別番号 + u64) より十分小さい
+        assert!(buf.len() < codes.len() * 4);
+    }
+
+    #[test]
+    fn test_binary_invalid() {
+        // 未知の識別番号
+        let buf = [1u8, 8, 0];
+        let err = read_binary(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        // 途中で途切れたデータ
+        let buf = [2u8, 2, 0x80];
+        let err = read_binary(&mut buf.as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
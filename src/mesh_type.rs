@@ -1,5 +1,54 @@
+use std::{cmp::Ordering, fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::{Coordinates, JPMeshError, Rect};
+
+/// 格子の原点の経度
+pub(crate) const ORIGIN_LNG: f64 = 100.0;
+
+/// 格子の原点の緯度
+pub(crate) const ORIGIN_LAT: f64 = 0.0;
+
+/// 第1次地域区画の経度方向の数 (経度100度〜180度)
+const MESH80KM_COLS: u64 = 80;
+
+/// 第1次地域区画の緯度方向の数 (緯度0度〜66度40分)
+const MESH80KM_ROWS: u64 = 100;
+
+/// メッシュコードの最大の桁数 (8分の1地域メッシュ)
+pub(crate) const MAX_CODE_LENGTH: usize = 11;
+
+const _: () = {
+    let all = JPMeshType::all();
+    let mut i = 0;
+    while i < all.len() {
+        assert!(all[i].code_length() <= MAX_CODE_LENGTH);
+        assert!(all[i].code_length() >= 4);
+        i += 1;
+    }
+};
+
+/// メッシュコードで表現できる範囲 (経度100度〜180度、緯度0度〜66度40分) を取得します。
+pub(crate) fn mesh_domain() -> Rect {
+    Rect::new(
+        Coordinates::new(ORIGIN_LNG, ORIGIN_LAT),
+        Coordinates::new(
+            ORIGIN_LNG + MESH80KM_COLS as f64 * JPMeshType::Mesh80km.lng_interval(),
+            ORIGIN_LAT + MESH80KM_ROWS as f64 * JPMeshType::Mesh80km.lat_interval(),
+        ),
+    )
+}
+
 /// 地域メッシュコードの種類
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// 総務省統計局の定める標準地域メッシュ (第1次地域区画〜基準地域メッシュ)、分割地域メッシュ (2分の1〜8分の1地域メッシュ)、
+/// 統合地域メッシュ (2倍・5倍地域メッシュ) に対応しています。
+///
+/// 基準地域メッシュを10×10に分割した100mメッシュ (10分の1細分区画、国土数値情報の土地利用細分メッシュ等で使用) には対応していません。
+/// 100mメッシュのコードは10桁で4分の1地域メッシュと桁数が重なり、末尾2桁の値 (00〜99) によっても区別できないため、
+/// 桁数から種類を推定する処理 (文字列からの変換、[`JPMesh::diagnose_number`](crate::JPMesh::diagnose_number)) が成り立ちません。
+/// また、[`MeshId`](crate::MeshId) の種類の識別番号 (3ビット) はすべて使用済みです。
+/// 必要な場合は、基準地域メッシュの範囲 ([`JPMesh::to_bounds`](crate::JPMesh::to_bounds)) を10×10に分割して扱ってください。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum JPMeshType {
     /// 第1次地域区画
     Mesh80km,
@@ -20,6 +69,129 @@ pub enum JPMeshType {
 }
 
 impl JPMeshType {
+    /// すべてのメッシュの種類を、大きいものから順に取得します。
+    pub const fn all() -> [JPMeshType; 8] {
+        [
+            JPMeshType::Mesh80km,
+            JPMeshType::Mesh10km,
+            JPMeshType::Mesh5km,
+            JPMeshType::Mesh2km,
+            JPMeshType::Mesh1km,
+            JPMeshType::Mesh500m,
+            JPMeshType::Mesh250m,
+            JPMeshType::Mesh125m,
+        ]
+    }
+
+    /// 第1次地域区画から8分の1地域メッシュまでの、標準的な階層に属する種類かどうかを判定します。
+    pub const fn is_standard(&self) -> bool {
+        !self.is_special_region()
+    }
+
+    /// 標準的な階層に属さない、統合地域メッシュ (5倍地域メッシュ・2倍地域メッシュ) かどうかを判定します。
+    pub const fn is_special_region(&self) -> bool {
+        matches!(self, JPMeshType::Mesh5km | JPMeshType::Mesh2km)
+    }
+
+    /// 総務省統計局による地域メッシュの次数 (第1次地域区画=1, 第2次地域区画=2, 基準地域メッシュ=3,
+    /// 2分の1地域メッシュ=4, 4分の1地域メッシュ=5, 8分の1地域メッシュ=6) を取得します。
+    ///
+    /// 5倍地域メッシュ・2倍地域メッシュには次数が定められていないため `None` を返します。
+    pub const fn standard_level_number(&self) -> Option<u8> {
+        match self {
+            JPMeshType::Mesh80km => Some(1),
+            JPMeshType::Mesh10km => Some(2),
+            JPMeshType::Mesh1km => Some(3),
+            JPMeshType::Mesh500m => Some(4),
+            JPMeshType::Mesh250m => Some(5),
+            JPMeshType::Mesh125m => Some(6),
+            JPMeshType::Mesh5km | JPMeshType::Mesh2km => None,
+        }
+    }
+
+    /// 総務省統計局による地域メッシュの次数から、メッシュの種類を取得します。
+    ///
+    /// 1〜6 以外の値に対しては `None` を返します。([`JPMeshType::standard_level_number`] を参照)
+    pub const fn from_standard_level_number(n: u8) -> Option<JPMeshType> {
+        match n {
+            1 => Some(JPMeshType::Mesh80km),
+            2 => Some(JPMeshType::Mesh10km),
+            3 => Some(JPMeshType::Mesh1km),
+            4 => Some(JPMeshType::Mesh500m),
+            5 => Some(JPMeshType::Mesh250m),
+            6 => Some(JPMeshType::Mesh125m),
+            _ => None,
+        }
+    }
+
+    /// 指定された大きさ (メートル) に最も近いメッシュの種類を取得します。
+    ///
+    /// 各メッシュの名目上の大きさ (80km, 10km, 5km, 2km, 1km, 500m, 250m, 125m) との比が最も1に近いものを選びます。
+    /// 1km・2km・5km の間の値もこの規則に従い、例えば 1500m は 2km、1400m は 1km となります。
+    /// 比が等しい場合は細かい方を選びます。0以下の値や NaN に対しては最も細かい `Mesh125m` を返します。
+    pub fn from_resolution_meters(m: f64) -> JPMeshType {
+        if m.is_nan() || m <= 0.0 {
+            return JPMeshType::Mesh125m;
+        }
+
+        let distance = |mesh_type: JPMeshType| (m / mesh_type.nominal_meters()).ln().abs();
+
+        // 細かいものから順に比較し、等しい場合は先に見つかった細かい方を残す
+        JPMeshType::all()
+            .into_iter()
+            .rev()
+            .fold(JPMeshType::Mesh125m, |best, mesh_type| {
+                if distance(mesh_type) < distance(best) {
+                    mesh_type
+                } else {
+                    best
+                }
+            })
+    }
+
+    /// メッシュの名目上の大きさを取得します。(メートル)
+    const fn nominal_meters(&self) -> f64 {
+        match self {
+            JPMeshType::Mesh80km => 80000.0,
+            JPMeshType::Mesh10km => 10000.0,
+            JPMeshType::Mesh1km => 1000.0,
+            JPMeshType::Mesh500m => 500.0,
+            JPMeshType::Mesh250m => 250.0,
+            JPMeshType::Mesh125m => 125.0,
+            JPMeshType::Mesh2km => 2000.0,
+            JPMeshType::Mesh5km => 5000.0,
+        }
+    }
+
+    /// メッシュの種類を表す文字列 (例: `1km`) を取得します。
+    const fn token(&self) -> &'static str {
+        match self {
+            JPMeshType::Mesh80km => "80km",
+            JPMeshType::Mesh10km => "10km",
+            JPMeshType::Mesh1km => "1km",
+            JPMeshType::Mesh500m => "500m",
+            JPMeshType::Mesh250m => "250m",
+            JPMeshType::Mesh125m => "125m",
+            JPMeshType::Mesh2km => "2km",
+            JPMeshType::Mesh5km => "5km",
+        }
+    }
+
+    /// メッシュの種類の日本語の名称 (例: `基準地域メッシュ`) を取得します。
+    pub(crate) const fn japanese_name(&self) -> &'static str {
+        match self {
+            JPMeshType::Mesh80km => "第1次地域区画",
+            JPMeshType::Mesh10km => "第2次地域区画",
+            JPMeshType::Mesh1km => "基準地域メッシュ",
+            JPMeshType::Mesh500m => "2分の1地域メッシュ",
+            JPMeshType::Mesh250m => "4分の1地域メッシュ",
+            JPMeshType::Mesh125m => "8分の1地域メッシュ",
+            JPMeshType::Mesh2km => "2倍地域メッシュ",
+            JPMeshType::Mesh5km => "5倍地域メッシュ",
+        }
+    }
+
+    /// メッシュコードの桁数を取得します。([`MAX_CODE_LENGTH`] 以下)
     pub(crate) const fn code_length(&self) -> usize {
         match self {
             JPMeshType::Mesh80km => 4,
@@ -33,7 +205,46 @@ impl JPMeshType {
         }
     }
 
-    const fn lat_interval_seconds(&self) -> f64 {
+    /// メッシュコードの各桁が取りうる値の範囲を、先頭の桁から順に取得します。
+    ///
+    /// 第1次地域区画の経度の桁 (3桁目) は 0〜7、第2次地域区画の分割番号 (5・6桁目) は 0〜7、
+    /// 5倍地域メッシュおよび2分の1以下の地域メッシュの分割番号は 1〜4、2倍地域メッシュの末尾は 5 です。
+    /// 2倍地域メッシュの7・8桁目は 0〜8 のうち偶数のみを取り、偶数であることはこの範囲では表しません。
+    pub fn code_digit_ranges(&self) -> Vec<RangeInclusive<u8>> {
+        let mut ranges = vec![0..=9, 0..=9, 0..=7, 0..=9];
+        if *self == JPMeshType::Mesh80km {
+            return ranges;
+        }
+        ranges.extend([0..=7, 0..=7]);
+        match self {
+            JPMeshType::Mesh5km => ranges.push(1..=4),
+            JPMeshType::Mesh2km => ranges.extend([0..=8, 0..=8, 5..=5]),
+            JPMeshType::Mesh10km => {}
+            _ => {
+                ranges.extend([0..=9, 0..=9]);
+                ranges.resize(self.code_length(), 1..=4);
+            }
+        }
+        ranges
+    }
+
+    /// この種類のメッシュコードのうち、指定された種類のメッシュコードに対応する先頭の桁数を取得します。
+    ///
+    /// 例えば、125m メッシュのコードの先頭8桁は 1km メッシュのコードです。
+    /// 指定された種類のメッシュコードが、この種類のメッシュコードの先頭部分とならない場合は `None` を返します。
+    pub fn code_prefix_length(&self, of: JPMeshType) -> Option<usize> {
+        use JPMeshType::*;
+
+        let is_prefix = match (self, of) {
+            _ if *self == of => true,
+            (Mesh2km | Mesh5km, Mesh80km | Mesh10km) => true,
+            (Mesh2km | Mesh5km, _) | (_, Mesh2km | Mesh5km) => false,
+            _ => of > *self,
+        };
+        is_prefix.then(|| of.code_length())
+    }
+
+    pub(crate) const fn lat_interval_seconds(&self) -> f64 {
         match self {
             JPMeshType::Mesh80km => 2400.0,
             JPMeshType::Mesh10km => 300.0,
@@ -46,7 +257,7 @@ impl JPMeshType {
         }
     }
 
-    const fn lng_interval_seconds(&self) -> f64 {
+    pub(crate) const fn lng_interval_seconds(&self) -> f64 {
         match self {
             JPMeshType::Mesh80km => 3600.0,
             JPMeshType::Mesh10km => 450.0,
@@ -68,4 +279,507 @@ impl JPMeshType {
     pub const fn lng_interval(&self) -> f64 {
         self.lng_interval_seconds() / 3600.0
     }
+
+    /// この種類のメッシュ1つを、指定された種類のメッシュに分割したときの分割数を取得します。(経度方向, 緯度方向)
+    ///
+    /// 指定された種類がこの種類より細かく、かつこの種類のメッシュに隙間なく含まれる場合のみ値を返します。
+    pub(crate) fn subdivision_factor(&self, finer: JPMeshType) -> Option<(u64, u64)> {
+        let lng = self.lng_interval_seconds() / finer.lng_interval_seconds();
+        let lat = self.lat_interval_seconds() / finer.lat_interval_seconds();
+
+        if lng <= 1.0 || lat <= 1.0 || lng.fract() != 0.0 || lat.fract() != 0.0 {
+            return None;
+        }
+
+        Some((lng as u64, lat as u64))
+    }
+
+    /// メッシュコードで表現できる範囲における、この種類のメッシュの格子の大きさを取得します。(経度方向, 緯度方向)
+    pub(crate) fn grid_size(&self) -> (u64, u64) {
+        let (lng_factor, lat_factor) = JPMeshType::Mesh80km
+            .subdivision_factor(*self)
+            .unwrap_or((1, 1));
+        (MESH80KM_COLS * lng_factor, MESH80KM_ROWS * lat_factor)
+    }
+
+    /// 格子上の位置 (経度方向の列番号, 緯度方向の行番号) にあるメッシュの範囲を表す矩形を取得します。
+    ///
+    /// 隣接するメッシュ同士や、包含関係にあるメッシュ同士の境界の座標は、浮動小数点数として完全に一致します。
+    pub(crate) fn grid_cell_bounds(&self, col: u64, row: u64) -> Rect {
+        let lng_seconds = self.lng_interval_seconds();
+        let lat_seconds = self.lat_interval_seconds();
+        Rect::new(
+            Coordinates::new(
+                grid_edge(ORIGIN_LNG, lng_seconds, col as f64),
+                grid_edge(ORIGIN_LAT, lat_seconds, row as f64),
+            ),
+            Coordinates::new(
+                grid_edge(ORIGIN_LNG, lng_seconds, (col + 1) as f64),
+                grid_edge(ORIGIN_LAT, lat_seconds, (row + 1) as f64),
+            ),
+        )
+    }
+
+    /// 指定された座標を含むメッシュの、格子上の位置 (経度方向の列番号, 緯度方向の行番号) を取得します。
+    ///
+    /// [`JPMeshType::grid_cell_bounds`] の矩形に座標が含まれるよう、浮動小数点の誤差を補正します。
+    /// メッシュコードで表現できる範囲の外にある場合は `None` を返します。
+    pub(crate) fn grid_index_of(&self, coords: Coordinates) -> Option<(u64, u64)> {
+        let (cols, rows) = self.grid_size();
+        let col = locate(coords.lng, ORIGIN_LNG, self.lng_interval_seconds(), cols)?;
+        let row = locate(coords.lat, ORIGIN_LAT, self.lat_interval_seconds(), rows)?;
+        Some((col, row))
+    }
+
+    /// 矩形と重なる、この種類のメッシュの格子上の位置の範囲を取得します。(南西端の位置, 北東端の位置)
+    ///
+    /// 矩形は南西端を含み北東端を含まないものとして扱い、幅が0の方向は南西端の座標を含むメッシュのみとします。
+    /// メッシュコードで表現できる範囲と重ならない場合や、矩形の向きが逆の場合は `None` を返します。
+    pub(crate) fn grid_index_range(&self, bounds: &Rect) -> Option<((u64, u64), (u64, u64))> {
+        let (cols, rows) = self.grid_size();
+        let (min, max) = (bounds.min(), bounds.max());
+        let (col0, col1) = axis_range(
+            min.lng,
+            max.lng,
+            ORIGIN_LNG,
+            self.lng_interval_seconds(),
+            cols,
+        )?;
+        let (row0, row1) = axis_range(
+            min.lat,
+            max.lat,
+            ORIGIN_LAT,
+            self.lat_interval_seconds(),
+            rows,
+        )?;
+        Some(((col0, row0), (col1, row1)))
+    }
+
+    /// 矩形と重なるこの種類のメッシュの数を、メッシュを生成せずに取得します。
+    ///
+    /// [`crate::JPMesh::from_on_bounds`] が生成するメッシュの数と一致します。
+    pub fn cell_count_over(&self, bounds: &Rect) -> u64 {
+        match self.grid_index_range(bounds) {
+            Some(((col0, row0), (col1, row1))) => (col1 - col0 + 1) * (row1 - row0 + 1),
+            None => 0,
+        }
+    }
+
+    /// メッシュの種類を識別する番号を取得します。(0〜7)
+    pub(crate) const fn tag(&self) -> u8 {
+        match self {
+            JPMeshType::Mesh80km => 0,
+            JPMeshType::Mesh10km => 1,
+            JPMeshType::Mesh1km => 2,
+            JPMeshType::Mesh500m => 3,
+            JPMeshType::Mesh250m => 4,
+            JPMeshType::Mesh125m => 5,
+            JPMeshType::Mesh2km => 6,
+            JPMeshType::Mesh5km => 7,
+        }
+    }
+
+    /// 識別番号からメッシュの種類を取得します。
+    pub(crate) const fn from_tag(tag: u8) -> Option<JPMeshType> {
+        match tag {
+            0 => Some(JPMeshType::Mesh80km),
+            1 => Some(JPMeshType::Mesh10km),
+            2 => Some(JPMeshType::Mesh1km),
+            3 => Some(JPMeshType::Mesh500m),
+            4 => Some(JPMeshType::Mesh250m),
+            5 => Some(JPMeshType::Mesh125m),
+            6 => Some(JPMeshType::Mesh2km),
+            7 => Some(JPMeshType::Mesh5km),
+            _ => None,
+        }
+    }
+}
+
+/// メッシュの種類は、1つのメッシュの面積 (緯度方向の幅 × 経度方向の幅) によって比較します。
+///
+/// 大きい種類ほど大きいものとして扱うため、2倍地域メッシュや5倍地域メッシュを含めて
+/// `Mesh125m < Mesh250m < Mesh500m < Mesh1km < Mesh2km < Mesh5km < Mesh10km < Mesh80km` となります。
+impl Ord for JPMeshType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let area = |mesh_type: &JPMeshType| {
+            mesh_type.lat_interval_seconds() * mesh_type.lng_interval_seconds()
+        };
+        area(self).total_cmp(&area(other))
+    }
+}
+
+impl PartialOrd for JPMeshType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// メッシュの種類を表す文字列 (例: `1km`, `500m`) を出力します。
+impl fmt::Display for JPMeshType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.token())
+    }
+}
+
+/// `1km` や `500m` などの文字列 (大文字・小文字は区別しません)、
+/// または `基準地域メッシュ` などの日本語の名称からメッシュの種類を解釈します。
+impl FromStr for JPMeshType {
+    type Err = JPMeshError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let token = s.trim();
+        JPMeshType::all()
+            .into_iter()
+            .find(|mesh_type| {
+                mesh_type.token().eq_ignore_ascii_case(token) || mesh_type.japanese_name() == token
+            })
+            .ok_or_else(|| JPMeshError::UnknownMeshType(s.to_string()))
+    }
+}
+
+/// 格子上の位置 `i` における境界の座標を取得します。
+///
+/// 秒単位の積は誤差なく計算できるため、度への変換は最後に1度だけ行います。
+fn grid_edge(origin: f64, interval_seconds: f64, i: f64) -> f64 {
+    origin + i * interval_seconds / 3600.0
+}
+
+/// 区間 `[min, max)` と重なるメッシュの位置の範囲を、1方向について取得します。
+///
+/// `min == max` の場合は `min` を含むメッシュのみとします。
+fn axis_range(
+    min: f64,
+    max: f64,
+    origin: f64,
+    interval_seconds: f64,
+    count: u64,
+) -> Option<(u64, u64)> {
+    if min.is_nan() || max.is_nan() || min > max {
+        return None;
+    }
+    if min == max {
+        let i = locate(min, origin, interval_seconds, count)?;
+        return Some((i, i));
+    }
+
+    let domain_max = grid_edge(origin, interval_seconds, count as f64);
+    if max <= origin || min >= domain_max {
+        return None;
+    }
+    let first = locate(min.max(origin), origin, interval_seconds, count)?;
+    let last = locate(
+        max.min(domain_max).next_down(),
+        origin,
+        interval_seconds,
+        count,
+    )?;
+    Some((first, last))
+}
+
+/// `grid_edge(i) <= value < grid_edge(i + 1)` を満たす `i` を取得します。
+fn locate(value: f64, origin: f64, interval_seconds: f64, count: u64) -> Option<u64> {
+    let mut i = ((value - origin) * 3600.0 / interval_seconds).floor();
+
+    if grid_edge(origin, interval_seconds, i) > value {
+        i -= 1.0;
+    } else if grid_edge(origin, interval_seconds, i + 1.0) <= value {
+        i += 1.0;
+    }
+
+    // NaN も範囲外として扱う
+    if !(i >= 0.0 && i < count as f64) {
+        return None;
+    }
+
+    Some(i as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_resolution_meters() {
+        assert_eq!(
+            JPMeshType::from_resolution_meters(500.0),
+            JPMeshType::Mesh500m
+        );
+        assert_eq!(
+            JPMeshType::from_resolution_meters(900.0),
+            JPMeshType::Mesh1km
+        );
+        assert_eq!(
+            JPMeshType::from_resolution_meters(1400.0),
+            JPMeshType::Mesh1km
+        );
+        assert_eq!(
+            JPMeshType::from_resolution_meters(1500.0),
+            JPMeshType::Mesh2km
+        );
+        assert_eq!(
+            JPMeshType::from_resolution_meters(4000.0),
+            JPMeshType::Mesh5km
+        );
+        assert_eq!(
+            JPMeshType::from_resolution_meters(1e6),
+            JPMeshType::Mesh80km
+        );
+        assert_eq!(
+            JPMeshType::from_resolution_meters(10.0),
+            JPMeshType::Mesh125m
+        );
+        assert_eq!(
+            JPMeshType::from_resolution_meters(0.0),
+            JPMeshType::Mesh125m
+        );
+    }
+
+    #[test]
+    fn test_mesh_type_display_from_str() {
+        for mesh_type in JPMeshType::all() {
+            assert_eq!(mesh_type.to_string().parse::<JPMeshType>(), Ok(mesh_type));
+        }
+        assert_eq!(JPMeshType::Mesh500m.to_string(), "500m");
+        assert_eq!(" 1KM ".parse::<JPMeshType>(), Ok(JPMeshType::Mesh1km));
+        assert_eq!(
+            "基準地域メッシュ".parse::<JPMeshType>(),
+            Ok(JPMeshType::Mesh1km)
+        );
+        assert_eq!(
+            "3km".parse::<JPMeshType>(),
+            Err(JPMeshError::UnknownMeshType("3km".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_cell_count_over() {
+        let mesh_type = JPMeshType::Mesh1km;
+
+        // メッシュの境界に揃った矩形
+        let bounds = Rect::new(
+            Coordinates::new(139.75, 35.5),
+            Coordinates::new(139.75 + 4.0 * 45.0 / 3600.0, 35.5 + 3.0 * 30.0 / 3600.0),
+        );
+        assert_eq!(mesh_type.cell_count_over(&bounds), 12);
+
+        // 境界をまたぐ小さな矩形
+        let edge = 139.75 + 45.0 / 3600.0;
+        let bounds = Rect::new(
+            Coordinates::new(edge - 0.001, 35.51),
+            Coordinates::new(edge + 0.001, 35.511),
+        );
+        assert_eq!(mesh_type.cell_count_over(&bounds), 2);
+
+        // 点
+        let point = Coordinates::new(139.76, 35.68);
+        assert_eq!(mesh_type.cell_count_over(&Rect::new(point, point)), 1);
+
+        // 範囲外や向きが逆の矩形
+        let outside = Rect::new(Coordinates::new(10.0, 10.0), Coordinates::new(20.0, 20.0));
+        assert_eq!(mesh_type.cell_count_over(&outside), 0);
+        let inverted = Rect::new(Coordinates::new(140.0, 36.0), Coordinates::new(139.0, 35.0));
+        assert_eq!(mesh_type.cell_count_over(&inverted), 0);
+
+        // 範囲の端で切り詰められる
+        let (cols, rows) = JPMeshType::Mesh80km.grid_size();
+        let everything = Rect::new(Coordinates::new(0.0, -90.0), Coordinates::new(360.0, 90.0));
+        assert_eq!(
+            JPMeshType::Mesh80km.cell_count_over(&everything),
+            cols * rows
+        );
+    }
+
+    #[test]
+    fn test_is_standard() {
+        for mesh_type in JPMeshType::all() {
+            let expected = match mesh_type {
+                JPMeshType::Mesh80km
+                | JPMeshType::Mesh10km
+                | JPMeshType::Mesh1km
+                | JPMeshType::Mesh500m
+                | JPMeshType::Mesh250m
+                | JPMeshType::Mesh125m => true,
+                JPMeshType::Mesh5km | JPMeshType::Mesh2km => false,
+            };
+            assert_eq!(mesh_type.is_standard(), expected);
+            assert_eq!(mesh_type.is_special_region(), !expected);
+            assert_eq!(
+                mesh_type.standard_level_number().is_some(),
+                mesh_type.is_standard()
+            );
+        }
+    }
+
+    #[test]
+    fn test_standard_level_number() {
+        for mesh_type in JPMeshType::all() {
+            if let Some(n) = mesh_type.standard_level_number() {
+                assert_eq!(JPMeshType::from_standard_level_number(n), Some(mesh_type));
+            }
+        }
+        assert_eq!(JPMeshType::Mesh1km.standard_level_number(), Some(3));
+        assert_eq!(JPMeshType::Mesh5km.standard_level_number(), None);
+        assert_eq!(JPMeshType::from_standard_level_number(0), None);
+        assert_eq!(JPMeshType::from_standard_level_number(7), None);
+    }
+
+    #[test]
+    fn test_code_prefix_length() {
+        assert_eq!(
+            JPMeshType::Mesh125m.code_prefix_length(JPMeshType::Mesh1km),
+            Some(8)
+        );
+        assert_eq!(
+            JPMeshType::Mesh500m.code_prefix_length(JPMeshType::Mesh10km),
+            Some(6)
+        );
+        assert_eq!(
+            JPMeshType::Mesh2km.code_prefix_length(JPMeshType::Mesh10km),
+            Some(6)
+        );
+        assert_eq!(
+            JPMeshType::Mesh1km.code_prefix_length(JPMeshType::Mesh1km),
+            Some(8)
+        );
+        assert_eq!(
+            JPMeshType::Mesh1km.code_prefix_length(JPMeshType::Mesh500m),
+            None
+        );
+        assert_eq!(
+            JPMeshType::Mesh1km.code_prefix_length(JPMeshType::Mesh5km),
+            None
+        );
+        assert_eq!(
+            JPMeshType::Mesh2km.code_prefix_length(JPMeshType::Mesh5km),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mesh_type_ordering() {
+        let mut mesh_types = vec![
+            JPMeshType::Mesh2km,
+            JPMeshType::Mesh125m,
+            JPMeshType::Mesh80km,
+            JPMeshType::Mesh5km,
+            JPMeshType::Mesh500m,
+            JPMeshType::Mesh1km,
+            JPMeshType::Mesh10km,
+            JPMeshType::Mesh250m,
+        ];
+        mesh_types.sort();
+
+        assert_eq!(
+            mesh_types,
+            vec![
+                JPMeshType::Mesh125m,
+                JPMeshType::Mesh250m,
+                JPMeshType::Mesh500m,
+                JPMeshType::Mesh1km,
+                JPMeshType::Mesh2km,
+                JPMeshType::Mesh5km,
+                JPMeshType::Mesh10km,
+                JPMeshType::Mesh80km,
+            ]
+        );
+
+        assert!(JPMeshType::Mesh5km > JPMeshType::Mesh2km);
+        assert!(JPMeshType::Mesh2km > JPMeshType::Mesh1km);
+        assert!(JPMeshType::Mesh10km > JPMeshType::Mesh5km);
+    }
+
+    #[test]
+    fn test_intervals() {
+        assert_eq!(JPMeshType::Mesh80km.lat_interval(), 2.0 / 3.0);
+        assert_eq!(JPMeshType::Mesh80km.lng_interval(), 1.0);
+        assert_eq!(JPMeshType::Mesh1km.lat_interval(), 30.0 / 3600.0);
+        assert_eq!(JPMeshType::Mesh1km.lng_interval(), 45.0 / 3600.0);
+
+        for mesh_type in JPMeshType::all() {
+            assert_eq!(
+                mesh_type.lat_interval(),
+                mesh_type.lat_interval_seconds() / 3600.0
+            );
+            assert_eq!(
+                mesh_type.lng_interval(),
+                mesh_type.lng_interval_seconds() / 3600.0
+            );
+        }
+    }
+
+    #[test]
+    fn test_grid_edge_seconds() {
+        // 秒単位の積を最後に1度だけ度に変換するため、境界は正しく丸められた値となる
+        // (度単位の幅との積 `53.0 * (2.0 / 3.0)` は 35.33333333333333 となり、一致しない)
+        assert_eq!(grid_edge(ORIGIN_LAT, 2400.0, 53.0), 106.0 / 3.0);
+        assert_eq!(grid_edge(ORIGIN_LAT, 30.0, 23.0), 23.0 / 120.0);
+        assert_eq!(grid_edge(ORIGIN_LNG, 3600.0, 39.0), 139.0);
+
+        // 包含関係にあるメッシュの境界は、浮動小数点数として完全に一致する
+        for coarse in JPMeshType::all() {
+            for fine in JPMeshType::all() {
+                let Some((lng_factor, lat_factor)) = coarse.subdivision_factor(fine) else {
+                    continue;
+                };
+                let (cols, rows) = coarse.grid_size();
+                for (col, row) in [(0, 0), (39, 53), (cols - 1, rows - 1), (17, 5)] {
+                    let outer = coarse.grid_cell_bounds(col, row);
+                    let south_west = fine.grid_cell_bounds(col * lng_factor, row * lat_factor);
+                    let north_east = fine
+                        .grid_cell_bounds((col + 1) * lng_factor - 1, (row + 1) * lat_factor - 1);
+                    assert_eq!(outer.min(), south_west.min(), "{:?} {:?}", coarse, fine);
+                    assert_eq!(outer.max(), north_east.max(), "{:?} {:?}", coarse, fine);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_locate_on_grid_edge() {
+        for (origin, interval_seconds) in [
+            (ORIGIN_LAT, 30.0),
+            (ORIGIN_LAT, 2400.0),
+            (ORIGIN_LNG, 5.625),
+        ] {
+            for i in 0..2000u64 {
+                let edge = grid_edge(origin, interval_seconds, i as f64);
+                // 境界上の座標は北側・東側のメッシュに含まれる
+                assert_eq!(locate(edge, origin, interval_seconds, 2000), Some(i));
+                if i > 0 {
+                    assert_eq!(
+                        locate(edge.next_down(), origin, interval_seconds, 2000),
+                        Some(i - 1)
+                    );
+                }
+            }
+        }
+        assert_eq!(locate(ORIGIN_LAT.next_down(), ORIGIN_LAT, 30.0, 2000), None);
+        assert_eq!(locate(f64::NAN, ORIGIN_LAT, 30.0, 2000), None);
+    }
+
+    #[test]
+    fn test_code_digit_ranges() {
+        for mesh_type in JPMeshType::all() {
+            assert_eq!(
+                mesh_type.code_digit_ranges().len(),
+                mesh_type.code_length(),
+                "{:?}",
+                mesh_type
+            );
+        }
+
+        assert_eq!(
+            JPMeshType::Mesh80km.code_digit_ranges(),
+            vec![0..=9, 0..=9, 0..=7, 0..=9]
+        );
+        assert_eq!(JPMeshType::Mesh5km.code_digit_ranges()[6], 1..=4);
+        assert_eq!(
+            JPMeshType::Mesh2km.code_digit_ranges()[6..],
+            [0..=8, 0..=8, 5..=5]
+        );
+        assert_eq!(
+            JPMeshType::Mesh125m.code_digit_ranges()[6..],
+            [0..=9, 0..=9, 1..=4, 1..=4, 1..=4]
+        );
+    }
 }
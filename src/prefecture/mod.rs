@@ -0,0 +1,24 @@
+mod table;
+
+use table::{PRIMARY_PREFECTURES, SECONDARY_PREFECTURES};
+
+/// 第2次地域区画 (10km) のメッシュコードから都道府県のコードを取得します。
+///
+/// 第2次地域区画ごとの対応表を引き、該当が無ければ上位の第1次地域区画 (80km) の都道府県を返します。
+/// 第1次地域区画が日本の地域メッシュ統計で定義されていない場合は `None` を返します。
+pub(crate) fn prefecture_code_of_secondary(code: u64) -> Option<u8> {
+    let secondary = u32::try_from(code).ok()?;
+    if let Ok(index) = SECONDARY_PREFECTURES.binary_search_by_key(&secondary, |(code, _)| *code) {
+        return Some(SECONDARY_PREFECTURES[index].1);
+    }
+    prefecture_code_of_primary(code / 100)
+}
+
+/// 第1次地域区画 (80km) のメッシュコードから、その区画で最も広い範囲を占める都道府県のコードを取得します。
+pub(crate) fn prefecture_code_of_primary(code: u64) -> Option<u8> {
+    let primary = u16::try_from(code).ok()?;
+    PRIMARY_PREFECTURES
+        .binary_search_by_key(&primary, |(code, _)| *code)
+        .ok()
+        .map(|index| PRIMARY_PREFECTURES[index].1)
+}
@@ -0,0 +1,1943 @@
+// このファイルは tools/prefecture_table.py で生成されています。直接編集しないでください。
+
+/// 第1次地域区画ごとの都道府県コード (メッシュコードの昇順)
+pub(super) const PRIMARY_PREFECTURES: [(u16, u8); 176] = [
+    (3036, 13),
+    (3622, 47),
+    (3623, 47),
+    (3624, 47),
+    (3631, 47),
+    (3641, 13),
+    (3653, 13),
+    (3724, 47),
+    (3725, 47),
+    (3741, 13),
+    (3823, 47),
+    (3824, 47),
+    (3831, 47),
+    (3841, 13),
+    (3926, 47),
+    (3927, 47),
+    (3928, 47),
+    (3942, 13),
+    (4027, 47),
+    (4028, 46),
+    (4040, 13),
+    (4042, 13),
+    (4128, 46),
+    (4129, 46),
+    (4142, 13),
+    (4229, 46),
+    (4230, 46),
+    (4328, 46),
+    (4329, 46),
+    (4429, 46),
+    (4440, 13),
+    (4529, 46),
+    (4530, 46),
+    (4531, 46),
+    (4540, 13),
+    (4629, 46),
+    (4630, 46),
+    (4631, 46),
+    (4728, 42),
+    (4729, 46),
+    (4730, 46),
+    (4731, 45),
+    (4739, 13),
+    (4740, 13),
+    (4828, 42),
+    (4829, 42),
+    (4830, 43),
+    (4831, 45),
+    (4839, 13),
+    (4928, 42),
+    (4929, 42),
+    (4930, 43),
+    (4931, 44),
+    (4932, 38),
+    (4933, 39),
+    (4934, 39),
+    (4939, 13),
+    (5029, 42),
+    (5030, 40),
+    (5031, 44),
+    (5032, 38),
+    (5033, 39),
+    (5034, 36),
+    (5035, 30),
+    (5036, 24),
+    (5038, 13),
+    (5039, 13),
+    (5129, 42),
+    (5130, 40),
+    (5131, 35),
+    (5132, 34),
+    (5133, 33),
+    (5134, 28),
+    (5135, 29),
+    (5136, 24),
+    (5137, 22),
+    (5138, 22),
+    (5139, 13),
+    (5229, 42),
+    (5231, 35),
+    (5232, 32),
+    (5233, 33),
+    (5234, 28),
+    (5235, 26),
+    (5236, 24),
+    (5237, 23),
+    (5238, 22),
+    (5239, 14),
+    (5240, 12),
+    (5332, 32),
+    (5333, 31),
+    (5334, 28),
+    (5335, 26),
+    (5336, 21),
+    (5337, 21),
+    (5338, 19),
+    (5339, 11),
+    (5340, 12),
+    (5432, 32),
+    (5433, 32),
+    (5435, 18),
+    (5436, 17),
+    (5437, 21),
+    (5438, 20),
+    (5439, 9),
+    (5440, 8),
+    (5531, 32),
+    (5536, 17),
+    (5537, 16),
+    (5538, 15),
+    (5539, 9),
+    (5540, 7),
+    (5541, 7),
+    (5636, 17),
+    (5637, 17),
+    (5638, 15),
+    (5639, 15),
+    (5640, 7),
+    (5641, 7),
+    (5738, 15),
+    (5739, 15),
+    (5740, 4),
+    (5741, 4),
+    (5839, 6),
+    (5840, 5),
+    (5841, 3),
+    (5939, 5),
+    (5940, 5),
+    (5941, 3),
+    (5942, 3),
+    (6039, 5),
+    (6040, 5),
+    (6041, 3),
+    (6139, 1),
+    (6140, 2),
+    (6141, 2),
+    (6239, 1),
+    (6240, 1),
+    (6241, 1),
+    (6243, 1),
+    (6339, 1),
+    (6340, 1),
+    (6341, 1),
+    (6342, 1),
+    (6343, 1),
+    (6439, 1),
+    (6440, 1),
+    (6441, 1),
+    (6442, 1),
+    (6443, 1),
+    (6444, 1),
+    (6445, 1),
+    (6540, 1),
+    (6541, 1),
+    (6542, 1),
+    (6543, 1),
+    (6544, 1),
+    (6545, 1),
+    (6546, 1),
+    (6641, 1),
+    (6642, 1),
+    (6643, 1),
+    (6644, 1),
+    (6645, 1),
+    (6646, 1),
+    (6647, 1),
+    (6740, 1),
+    (6741, 1),
+    (6742, 1),
+    (6747, 1),
+    (6748, 1),
+    (6840, 1),
+    (6841, 1),
+    (6842, 1),
+    (6847, 1),
+    (6848, 1),
+];
+
+/// 第1次地域区画の都道府県コードと異なる第2次地域区画の都道府県コード (メッシュコードの昇順)
+pub(super) const SECONDARY_PREFECTURES: [(u32, u8); 1758] = [
+    (392877, 46),
+    (402800, 47),
+    (402801, 47),
+    (402802, 47),
+    (402803, 47),
+    (402804, 47),
+    (402805, 47),
+    (402810, 47),
+    (402811, 47),
+    (402812, 47),
+    (402813, 47),
+    (402814, 47),
+    (402820, 47),
+    (402821, 47),
+    (402822, 47),
+    (402823, 47),
+    (402830, 47),
+    (402831, 47),
+    (402832, 47),
+    (402840, 47),
+    (402841, 47),
+    (402850, 47),
+    (402860, 47),
+    (402870, 47),
+    (412800, 47),
+    (412810, 47),
+    (412820, 47),
+    (463127, 45),
+    (463135, 45),
+    (463136, 45),
+    (463137, 45),
+    (463143, 45),
+    (463144, 45),
+    (463145, 45),
+    (463146, 45),
+    (463147, 45),
+    (463153, 45),
+    (463154, 45),
+    (463155, 45),
+    (463156, 45),
+    (463157, 45),
+    (463162, 45),
+    (463163, 45),
+    (463164, 45),
+    (463165, 45),
+    (463166, 45),
+    (463167, 45),
+    (463172, 45),
+    (463173, 45),
+    (463174, 45),
+    (463175, 45),
+    (463176, 45),
+    (463177, 45),
+    (472807, 46),
+    (472817, 46),
+    (472970, 42),
+    (473067, 45),
+    (473077, 45),
+    (473100, 46),
+    (473110, 46),
+    (473120, 46),
+    (473130, 46),
+    (482901, 46),
+    (482902, 46),
+    (482903, 46),
+    (482904, 46),
+    (482905, 46),
+    (482906, 46),
+    (482907, 43),
+    (482911, 46),
+    (482912, 46),
+    (482913, 46),
+    (482914, 43),
+    (482915, 43),
+    (482916, 43),
+    (482917, 43),
+    (482924, 43),
+    (482925, 43),
+    (482926, 43),
+    (482927, 43),
+    (482936, 43),
+    (482937, 43),
+    (482947, 43),
+    (482957, 43),
+    (482967, 43),
+    (483000, 46),
+    (483001, 46),
+    (483002, 46),
+    (483003, 46),
+    (483004, 46),
+    (483005, 46),
+    (483006, 45),
+    (483007, 45),
+    (483011, 46),
+    (483012, 46),
+    (483013, 46),
+    (483014, 46),
+    (483015, 46),
+    (483021, 46),
+    (483071, 42),
+    (483072, 42),
+    (483120, 43),
+    (483130, 43),
+    (483170, 43),
+    (483171, 43),
+    (492957, 41),
+    (492966, 41),
+    (492967, 41),
+    (492977, 41),
+    (493000, 42),
+    (493001, 42),
+    (493002, 42),
+    (493003, 42),
+    (493010, 42),
+    (493011, 42),
+    (493012, 42),
+    (493013, 42),
+    (493020, 42),
+    (493021, 42),
+    (493022, 42),
+    (493023, 42),
+    (493030, 42),
+    (493031, 41),
+    (493040, 41),
+    (493041, 41),
+    (493042, 41),
+    (493043, 40),
+    (493050, 41),
+    (493051, 41),
+    (493052, 40),
+    (493053, 40),
+    (493054, 40),
+    (493057, 44),
+    (493060, 41),
+    (493061, 41),
+    (493062, 41),
+    (493063, 40),
+    (493064, 40),
+    (493065, 40),
+    (493066, 40),
+    (493067, 44),
+    (493070, 41),
+    (493071, 41),
+    (493072, 41),
+    (493073, 41),
+    (493074, 40),
+    (493075, 40),
+    (493076, 40),
+    (493077, 44),
+    (493100, 43),
+    (493101, 45),
+    (493102, 45),
+    (493103, 45),
+    (493104, 45),
+    (493105, 45),
+    (493106, 45),
+    (493110, 43),
+    (493111, 43),
+    (493112, 45),
+    (493113, 45),
+    (493114, 45),
+    (493120, 43),
+    (493121, 43),
+    (493130, 43),
+    (493131, 43),
+    (493140, 43),
+    (493141, 43),
+    (493150, 43),
+    (493200, 44),
+    (493201, 44),
+    (493202, 39),
+    (493203, 39),
+    (493204, 39),
+    (493205, 39),
+    (493206, 39),
+    (493207, 39),
+    (493210, 44),
+    (493211, 44),
+    (493212, 39),
+    (493213, 39),
+    (493214, 39),
+    (493215, 39),
+    (493216, 39),
+    (493217, 39),
+    (493220, 44),
+    (493221, 44),
+    (493225, 39),
+    (493226, 39),
+    (493227, 39),
+    (493230, 44),
+    (493231, 44),
+    (493236, 39),
+    (493237, 39),
+    (493240, 44),
+    (493241, 44),
+    (493246, 39),
+    (493247, 39),
+    (493250, 44),
+    (493256, 39),
+    (493257, 39),
+    (493260, 44),
+    (493267, 39),
+    (493277, 39),
+    (493447, 30),
+    (493457, 30),
+    (493467, 30),
+    (493476, 36),
+    (493477, 30),
+    (502907, 41),
+    (502916, 41),
+    (502917, 41),
+    (502926, 41),
+    (502927, 41),
+    (502936, 41),
+    (502937, 41),
+    (502947, 41),
+    (503000, 41),
+    (503001, 41),
+    (503002, 41),
+    (503003, 41),
+    (503007, 44),
+    (503010, 41),
+    (503011, 41),
+    (503060, 42),
+    (503077, 35),
+    (503117, 38),
+    (503120, 40),
+    (503121, 40),
+    (503130, 40),
+    (503140, 40),
+    (503141, 40),
+    (503147, 35),
+    (503150, 40),
+    (503151, 40),
+    (503152, 35),
+    (503157, 35),
+    (503160, 40),
+    (503161, 35),
+    (503162, 35),
+    (503163, 35),
+    (503166, 35),
+    (503167, 35),
+    (503170, 40),
+    (503171, 35),
+    (503172, 35),
+    (503173, 35),
+    (503174, 35),
+    (503175, 35),
+    (503176, 35),
+    (503177, 35),
+    (503207, 39),
+    (503217, 39),
+    (503230, 35),
+    (503240, 35),
+    (503241, 35),
+    (503242, 35),
+    (503250, 35),
+    (503251, 35),
+    (503252, 35),
+    (503260, 35),
+    (503261, 35),
+    (503262, 35),
+    (503270, 35),
+    (503271, 35),
+    (503272, 35),
+    (503320, 38),
+    (503330, 38),
+    (503340, 38),
+    (503341, 38),
+    (503350, 38),
+    (503351, 38),
+    (503352, 38),
+    (503357, 36),
+    (503360, 38),
+    (503361, 38),
+    (503362, 38),
+    (503363, 38),
+    (503364, 38),
+    (503365, 38),
+    (503366, 36),
+    (503367, 36),
+    (503370, 38),
+    (503371, 38),
+    (503372, 38),
+    (503373, 38),
+    (503374, 38),
+    (503375, 36),
+    (503376, 36),
+    (503377, 36),
+    (503400, 39),
+    (503401, 39),
+    (503402, 39),
+    (503403, 39),
+    (503404, 39),
+    (503407, 30),
+    (503410, 39),
+    (503411, 39),
+    (503412, 39),
+    (503413, 39),
+    (503417, 30),
+    (503420, 39),
+    (503421, 39),
+    (503422, 39),
+    (503427, 30),
+    (503430, 39),
+    (503431, 39),
+    (503437, 30),
+    (503440, 39),
+    (503447, 30),
+    (503457, 30),
+    (503467, 30),
+    (503477, 30),
+    (503557, 24),
+    (503565, 29),
+    (503566, 29),
+    (503575, 29),
+    (503576, 29),
+    (503577, 29),
+    (503600, 30),
+    (503601, 30),
+    (503602, 30),
+    (503603, 30),
+    (503604, 30),
+    (503605, 30),
+    (503606, 30),
+    (503610, 30),
+    (503611, 30),
+    (503612, 30),
+    (503613, 30),
+    (503614, 30),
+    (503620, 30),
+    (503621, 30),
+    (503622, 30),
+    (503630, 30),
+    (512907, 40),
+    (512916, 40),
+    (512917, 40),
+    (512926, 40),
+    (512927, 40),
+    (512936, 40),
+    (512937, 40),
+    (512946, 40),
+    (512947, 40),
+    (512956, 40),
+    (512957, 40),
+    (512967, 40),
+    (513006, 35),
+    (513007, 35),
+    (513016, 35),
+    (513017, 35),
+    (513024, 35),
+    (513025, 35),
+    (513026, 35),
+    (513027, 35),
+    (513034, 35),
+    (513035, 35),
+    (513036, 35),
+    (513037, 35),
+    (513044, 35),
+    (513045, 35),
+    (513046, 35),
+    (513047, 35),
+    (513054, 35),
+    (513055, 35),
+    (513056, 35),
+    (513057, 35),
+    (513064, 35),
+    (513065, 35),
+    (513066, 35),
+    (513067, 35),
+    (513074, 35),
+    (513075, 35),
+    (513076, 35),
+    (513077, 35),
+    (513146, 32),
+    (513147, 32),
+    (513156, 32),
+    (513157, 32),
+    (513166, 32),
+    (513167, 32),
+    (513176, 32),
+    (513177, 32),
+    (513200, 35),
+    (513201, 35),
+    (513202, 35),
+    (513204, 38),
+    (513205, 38),
+    (513206, 38),
+    (513207, 38),
+    (513210, 35),
+    (513211, 35),
+    (513212, 35),
+    (513217, 38),
+    (513220, 35),
+    (513221, 35),
+    (513230, 35),
+    (513260, 32),
+    (513270, 32),
+    (513300, 38),
+    (513301, 38),
+    (513302, 38),
+    (513303, 38),
+    (513304, 38),
+    (513305, 37),
+    (513306, 36),
+    (513307, 36),
+    (513310, 38),
+    (513311, 38),
+    (513312, 38),
+    (513313, 38),
+    (513314, 37),
+    (513315, 37),
+    (513316, 37),
+    (513317, 37),
+    (513320, 38),
+    (513321, 38),
+    (513322, 38),
+    (513323, 34),
+    (513324, 37),
+    (513325, 37),
+    (513326, 37),
+    (513327, 37),
+    (513330, 38),
+    (513331, 34),
+    (513332, 38),
+    (513333, 34),
+    (513335, 37),
+    (513336, 37),
+    (513337, 37),
+    (513340, 34),
+    (513341, 34),
+    (513342, 34),
+    (513343, 34),
+    (513346, 37),
+    (513347, 37),
+    (513350, 34),
+    (513351, 34),
+    (513352, 34),
+    (513353, 34),
+    (513360, 34),
+    (513361, 34),
+    (513362, 34),
+    (513370, 34),
+    (513371, 34),
+    (513372, 34),
+    (513400, 36),
+    (513401, 36),
+    (513402, 36),
+    (513403, 36),
+    (513404, 36),
+    (513405, 36),
+    (513407, 30),
+    (513410, 36),
+    (513411, 36),
+    (513412, 36),
+    (513413, 36),
+    (513414, 36),
+    (513415, 36),
+    (513420, 37),
+    (513421, 37),
+    (513422, 37),
+    (513423, 37),
+    (513424, 36),
+    (513425, 36),
+    (513430, 37),
+    (513431, 37),
+    (513432, 37),
+    (513433, 37),
+    (513434, 36),
+    (513440, 37),
+    (513441, 37),
+    (513442, 37),
+    (513443, 37),
+    (513450, 37),
+    (513451, 37),
+    (513452, 37),
+    (513453, 37),
+    (513460, 37),
+    (513461, 37),
+    (513462, 37),
+    (513463, 37),
+    (513470, 33),
+    (513471, 33),
+    (513472, 37),
+    (513500, 30),
+    (513501, 30),
+    (513502, 30),
+    (513503, 30),
+    (513510, 30),
+    (513511, 30),
+    (513512, 30),
+    (513513, 30),
+    (513520, 30),
+    (513521, 30),
+    (513522, 30),
+    (513523, 30),
+    (513524, 30),
+    (513530, 27),
+    (513531, 27),
+    (513532, 30),
+    (513533, 30),
+    (513534, 30),
+    (513540, 27),
+    (513541, 27),
+    (513542, 27),
+    (513543, 27),
+    (513544, 27),
+    (513550, 28),
+    (513551, 27),
+    (513552, 27),
+    (513553, 27),
+    (513554, 27),
+    (513560, 28),
+    (513561, 28),
+    (513562, 27),
+    (513563, 27),
+    (513564, 27),
+    (513570, 28),
+    (513571, 28),
+    (513572, 28),
+    (513573, 27),
+    (513574, 27),
+    (513600, 29),
+    (513610, 29),
+    (513620, 29),
+    (513630, 29),
+    (513640, 29),
+    (513650, 29),
+    (513651, 29),
+    (513660, 29),
+    (513661, 29),
+    (513667, 23),
+    (513676, 23),
+    (513677, 23),
+    (513700, 24),
+    (513701, 24),
+    (513702, 24),
+    (513703, 24),
+    (513704, 24),
+    (513710, 24),
+    (513711, 24),
+    (513712, 24),
+    (513713, 24),
+    (513720, 24),
+    (513721, 24),
+    (513722, 24),
+    (513723, 23),
+    (513730, 24),
+    (513731, 24),
+    (513732, 23),
+    (513733, 23),
+    (513740, 24),
+    (513741, 23),
+    (513742, 23),
+    (513743, 23),
+    (513750, 23),
+    (513751, 23),
+    (513752, 23),
+    (513753, 23),
+    (513760, 23),
+    (513761, 23),
+    (513762, 23),
+    (513770, 23),
+    (513771, 23),
+    (513772, 23),
+    (513801, 13),
+    (513802, 13),
+    (513803, 13),
+    (513804, 13),
+    (513805, 13),
+    (513806, 13),
+    (513807, 13),
+    (513812, 13),
+    (513813, 13),
+    (513814, 13),
+    (513815, 13),
+    (513816, 13),
+    (513817, 13),
+    (513823, 13),
+    (513824, 13),
+    (513825, 13),
+    (513826, 13),
+    (513827, 13),
+    (513834, 13),
+    (513835, 13),
+    (513836, 13),
+    (513837, 13),
+    (513847, 13),
+    (513957, 12),
+    (513960, 22),
+    (513966, 12),
+    (513967, 12),
+    (513970, 22),
+    (513976, 12),
+    (513977, 12),
+    (523106, 32),
+    (523107, 32),
+    (523115, 32),
+    (523116, 32),
+    (523117, 32),
+    (523125, 32),
+    (523126, 32),
+    (523127, 32),
+    (523135, 32),
+    (523136, 32),
+    (523137, 32),
+    (523145, 32),
+    (523146, 32),
+    (523147, 32),
+    (523154, 32),
+    (523155, 32),
+    (523156, 32),
+    (523157, 32),
+    (523164, 32),
+    (523165, 32),
+    (523166, 32),
+    (523167, 32),
+    (523174, 32),
+    (523175, 32),
+    (523176, 32),
+    (523177, 32),
+    (523201, 34),
+    (523202, 34),
+    (523203, 34),
+    (523204, 34),
+    (523205, 34),
+    (523206, 34),
+    (523207, 34),
+    (523212, 34),
+    (523213, 34),
+    (523215, 34),
+    (523216, 34),
+    (523217, 34),
+    (523225, 34),
+    (523226, 34),
+    (523227, 34),
+    (523235, 34),
+    (523236, 34),
+    (523237, 34),
+    (523247, 34),
+    (523300, 34),
+    (523301, 34),
+    (523302, 34),
+    (523310, 34),
+    (523311, 34),
+    (523312, 34),
+    (523320, 34),
+    (523321, 34),
+    (523322, 34),
+    (523330, 34),
+    (523331, 34),
+    (523340, 34),
+    (523341, 34),
+    (523342, 31),
+    (523350, 32),
+    (523351, 31),
+    (523352, 31),
+    (523360, 32),
+    (523361, 31),
+    (523362, 31),
+    (523363, 31),
+    (523370, 32),
+    (523371, 32),
+    (523372, 31),
+    (523373, 31),
+    (523374, 31),
+    (523400, 33),
+    (523401, 33),
+    (523410, 33),
+    (523411, 33),
+    (523420, 33),
+    (523421, 33),
+    (523430, 33),
+    (523431, 33),
+    (523440, 33),
+    (523441, 33),
+    (523450, 33),
+    (523451, 33),
+    (523452, 31),
+    (523460, 33),
+    (523461, 31),
+    (523462, 33),
+    (523467, 26),
+    (523470, 31),
+    (523471, 31),
+    (523472, 31),
+    (523473, 31),
+    (523474, 31),
+    (523477, 26),
+    (523500, 28),
+    (523501, 28),
+    (523502, 28),
+    (523503, 27),
+    (523504, 27),
+    (523505, 29),
+    (523507, 29),
+    (523510, 28),
+    (523511, 28),
+    (523512, 28),
+    (523513, 27),
+    (523514, 27),
+    (523515, 27),
+    (523520, 28),
+    (523521, 28),
+    (523522, 28),
+    (523523, 28),
+    (523524, 27),
+    (523530, 28),
+    (523531, 28),
+    (523532, 28),
+    (523533, 27),
+    (523534, 27),
+    (523537, 25),
+    (523540, 28),
+    (523541, 28),
+    (523542, 28),
+    (523543, 27),
+    (523546, 25),
+    (523547, 25),
+    (523550, 28),
+    (523551, 28),
+    (523552, 28),
+    (523556, 25),
+    (523557, 25),
+    (523560, 28),
+    (523561, 28),
+    (523567, 25),
+    (523577, 25),
+    (523600, 29),
+    (523606, 23),
+    (523607, 23),
+    (523610, 26),
+    (523616, 23),
+    (523617, 23),
+    (523620, 25),
+    (523622, 25),
+    (523626, 23),
+    (523627, 23),
+    (523630, 25),
+    (523631, 25),
+    (523632, 25),
+    (523636, 23),
+    (523637, 23),
+    (523640, 25),
+    (523641, 25),
+    (523642, 25),
+    (523646, 23),
+    (523647, 23),
+    (523650, 25),
+    (523651, 25),
+    (523652, 25),
+    (523655, 23),
+    (523656, 23),
+    (523657, 23),
+    (523660, 25),
+    (523661, 25),
+    (523662, 25),
+    (523663, 25),
+    (523664, 21),
+    (523665, 21),
+    (523666, 23),
+    (523667, 23),
+    (523670, 25),
+    (523671, 25),
+    (523672, 25),
+    (523673, 21),
+    (523674, 21),
+    (523675, 21),
+    (523676, 23),
+    (523677, 23),
+    (523704, 22),
+    (523705, 22),
+    (523706, 22),
+    (523707, 22),
+    (523714, 22),
+    (523715, 22),
+    (523716, 22),
+    (523717, 22),
+    (523725, 22),
+    (523726, 22),
+    (523727, 22),
+    (523736, 22),
+    (523737, 22),
+    (523746, 22),
+    (523747, 22),
+    (523756, 22),
+    (523757, 22),
+    (523763, 21),
+    (523766, 20),
+    (523767, 22),
+    (523771, 21),
+    (523772, 21),
+    (523773, 21),
+    (523774, 20),
+    (523775, 20),
+    (523776, 20),
+    (523777, 20),
+    (523863, 19),
+    (523873, 19),
+    (523900, 22),
+    (523901, 13),
+    (523902, 13),
+    (523903, 13),
+    (523904, 13),
+    (523905, 12),
+    (523906, 12),
+    (523907, 12),
+    (523910, 22),
+    (523911, 22),
+    (523912, 13),
+    (523913, 13),
+    (523914, 13),
+    (523915, 12),
+    (523916, 12),
+    (523917, 12),
+    (523920, 22),
+    (523921, 22),
+    (523922, 13),
+    (523923, 13),
+    (523924, 13),
+    (523925, 12),
+    (523926, 12),
+    (523927, 12),
+    (523930, 22),
+    (523931, 22),
+    (523932, 22),
+    (523933, 13),
+    (523935, 12),
+    (523936, 12),
+    (523937, 12),
+    (523940, 22),
+    (523941, 22),
+    (523946, 12),
+    (523947, 12),
+    (523950, 22),
+    (523956, 12),
+    (523957, 12),
+    (523966, 12),
+    (523967, 12),
+    (523976, 12),
+    (523977, 12),
+    (533300, 32),
+    (533301, 32),
+    (533310, 32),
+    (533311, 32),
+    (533320, 32),
+    (533322, 32),
+    (533330, 32),
+    (533332, 32),
+    (533333, 32),
+    (533342, 32),
+    (533343, 32),
+    (533350, 32),
+    (533351, 32),
+    (533352, 32),
+    (533353, 32),
+    (533360, 32),
+    (533361, 32),
+    (533362, 32),
+    (533363, 32),
+    (533364, 32),
+    (533370, 32),
+    (533371, 32),
+    (533372, 32),
+    (533373, 32),
+    (533374, 32),
+    (533375, 32),
+    (533400, 31),
+    (533401, 31),
+    (533402, 31),
+    (533403, 31),
+    (533404, 31),
+    (533410, 31),
+    (533411, 31),
+    (533412, 31),
+    (533420, 31),
+    (533421, 31),
+    (533422, 31),
+    (533427, 26),
+    (533430, 31),
+    (533431, 31),
+    (533432, 31),
+    (533437, 26),
+    (533440, 31),
+    (533441, 31),
+    (533442, 31),
+    (533447, 26),
+    (533450, 31),
+    (533451, 31),
+    (533457, 26),
+    (533460, 31),
+    (533461, 31),
+    (533467, 26),
+    (533470, 31),
+    (533477, 26),
+    (533504, 18),
+    (533505, 18),
+    (533507, 25),
+    (533510, 28),
+    (533514, 18),
+    (533515, 18),
+    (533516, 18),
+    (533517, 18),
+    (533524, 18),
+    (533525, 18),
+    (533526, 18),
+    (533527, 18),
+    (533534, 18),
+    (533535, 18),
+    (533536, 18),
+    (533537, 18),
+    (533544, 18),
+    (533545, 18),
+    (533546, 18),
+    (533547, 18),
+    (533555, 18),
+    (533556, 18),
+    (533557, 18),
+    (533565, 18),
+    (533566, 18),
+    (533567, 18),
+    (533574, 18),
+    (533575, 18),
+    (533576, 18),
+    (533577, 18),
+    (533600, 25),
+    (533601, 25),
+    (533602, 25),
+    (533606, 23),
+    (533607, 23),
+    (533610, 25),
+    (533611, 25),
+    (533612, 25),
+    (533620, 25),
+    (533621, 25),
+    (533622, 25),
+    (533630, 18),
+    (533631, 18),
+    (533640, 18),
+    (533641, 18),
+    (533650, 18),
+    (533651, 18),
+    (533652, 18),
+    (533653, 18),
+    (533654, 18),
+    (533655, 18),
+    (533660, 18),
+    (533661, 18),
+    (533662, 18),
+    (533663, 18),
+    (533664, 18),
+    (533665, 18),
+    (533670, 18),
+    (533671, 18),
+    (533672, 18),
+    (533673, 18),
+    (533674, 18),
+    (533675, 18),
+    (533704, 20),
+    (533705, 20),
+    (533706, 20),
+    (533707, 20),
+    (533715, 20),
+    (533716, 20),
+    (533717, 20),
+    (533725, 20),
+    (533726, 20),
+    (533727, 20),
+    (533734, 20),
+    (533735, 20),
+    (533736, 20),
+    (533737, 20),
+    (533744, 20),
+    (533745, 20),
+    (533746, 20),
+    (533747, 20),
+    (533754, 20),
+    (533755, 20),
+    (533756, 20),
+    (533757, 20),
+    (533764, 20),
+    (533765, 20),
+    (533766, 20),
+    (533767, 20),
+    (533775, 20),
+    (533776, 20),
+    (533777, 20),
+    (533800, 20),
+    (533801, 22),
+    (533804, 22),
+    (533805, 22),
+    (533807, 22),
+    (533810, 20),
+    (533817, 14),
+    (533820, 20),
+    (533830, 20),
+    (533840, 20),
+    (533841, 20),
+    (533850, 20),
+    (533851, 20),
+    (533860, 20),
+    (533864, 20),
+    (533867, 11),
+    (533870, 20),
+    (533871, 20),
+    (533873, 20),
+    (533874, 20),
+    (533875, 20),
+    (533876, 11),
+    (533877, 11),
+    (533900, 14),
+    (533901, 14),
+    (533902, 14),
+    (533903, 14),
+    (533904, 14),
+    (533905, 14),
+    (533906, 12),
+    (533907, 12),
+    (533910, 14),
+    (533911, 14),
+    (533912, 14),
+    (533913, 14),
+    (533914, 14),
+    (533915, 14),
+    (533916, 12),
+    (533917, 12),
+    (533920, 19),
+    (533921, 14),
+    (533922, 14),
+    (533923, 13),
+    (533924, 14),
+    (533925, 14),
+    (533926, 13),
+    (533927, 12),
+    (533930, 19),
+    (533931, 14),
+    (533932, 13),
+    (533933, 13),
+    (533934, 13),
+    (533935, 13),
+    (533936, 12),
+    (533937, 12),
+    (533940, 13),
+    (533941, 13),
+    (533942, 13),
+    (533943, 13),
+    (533944, 13),
+    (533945, 13),
+    (533946, 13),
+    (533947, 12),
+    (533950, 13),
+    (533951, 13),
+    (533952, 13),
+    (533957, 12),
+    (533960, 13),
+    (533967, 12),
+    (533977, 12),
+    (534060, 8),
+    (534061, 8),
+    (534062, 8),
+    (534065, 8),
+    (534070, 8),
+    (534071, 8),
+    (534072, 8),
+    (534073, 8),
+    (534074, 8),
+    (534075, 8),
+    (534076, 8),
+    (534077, 8),
+    (543500, 26),
+    (543501, 26),
+    (543502, 26),
+    (543503, 26),
+    (543510, 26),
+    (543511, 26),
+    (543512, 26),
+    (543513, 26),
+    (543520, 26),
+    (543521, 26),
+    (543522, 26),
+    (543530, 26),
+    (543531, 26),
+    (543532, 26),
+    (543540, 26),
+    (543541, 26),
+    (543550, 26),
+    (543551, 26),
+    (543557, 17),
+    (543560, 26),
+    (543566, 17),
+    (543567, 17),
+    (543570, 26),
+    (543575, 17),
+    (543576, 17),
+    (543577, 17),
+    (543600, 18),
+    (543601, 18),
+    (543602, 18),
+    (543603, 18),
+    (543604, 18),
+    (543605, 18),
+    (543606, 21),
+    (543607, 21),
+    (543610, 18),
+    (543611, 18),
+    (543612, 18),
+    (543613, 18),
+    (543616, 21),
+    (543617, 21),
+    (543620, 18),
+    (543621, 18),
+    (543626, 21),
+    (543627, 21),
+    (543630, 18),
+    (543631, 18),
+    (543636, 21),
+    (543637, 21),
+    (543640, 18),
+    (543646, 16),
+    (543647, 16),
+    (543657, 16),
+    (543666, 16),
+    (543667, 16),
+    (543676, 16),
+    (543677, 16),
+    (543705, 20),
+    (543706, 20),
+    (543707, 20),
+    (543715, 20),
+    (543716, 20),
+    (543717, 20),
+    (543725, 20),
+    (543726, 20),
+    (543727, 20),
+    (543735, 20),
+    (543736, 20),
+    (543737, 20),
+    (543743, 16),
+    (543744, 16),
+    (543745, 20),
+    (543746, 20),
+    (543747, 20),
+    (543750, 16),
+    (543752, 16),
+    (543753, 16),
+    (543754, 16),
+    (543755, 16),
+    (543756, 20),
+    (543757, 20),
+    (543760, 16),
+    (543761, 16),
+    (543762, 16),
+    (543763, 16),
+    (543764, 16),
+    (543765, 16),
+    (543766, 20),
+    (543767, 20),
+    (543770, 16),
+    (543771, 16),
+    (543772, 16),
+    (543773, 16),
+    (543774, 16),
+    (543775, 20),
+    (543776, 20),
+    (543777, 20),
+    (543805, 10),
+    (543806, 10),
+    (543807, 11),
+    (543815, 10),
+    (543816, 10),
+    (543817, 10),
+    (543825, 10),
+    (543826, 10),
+    (543827, 10),
+    (543836, 10),
+    (543837, 10),
+    (543846, 10),
+    (543847, 10),
+    (543853, 10),
+    (543854, 10),
+    (543855, 10),
+    (543856, 10),
+    (543857, 10),
+    (543863, 10),
+    (543864, 10),
+    (543865, 10),
+    (543866, 10),
+    (543867, 10),
+    (543874, 10),
+    (543875, 10),
+    (543876, 10),
+    (543877, 10),
+    (543900, 11),
+    (543901, 11),
+    (543902, 11),
+    (543903, 11),
+    (543904, 11),
+    (543905, 11),
+    (543906, 12),
+    (543907, 8),
+    (543910, 11),
+    (543911, 11),
+    (543912, 11),
+    (543913, 11),
+    (543914, 11),
+    (543915, 11),
+    (543916, 8),
+    (543917, 8),
+    (543920, 11),
+    (543921, 11),
+    (543922, 11),
+    (543923, 10),
+    (543924, 10),
+    (543925, 11),
+    (543926, 8),
+    (543927, 8),
+    (543930, 10),
+    (543931, 10),
+    (543932, 10),
+    (543933, 10),
+    (543937, 8),
+    (543940, 10),
+    (543941, 10),
+    (543942, 10),
+    (543947, 8),
+    (543950, 10),
+    (543951, 10),
+    (543952, 10),
+    (543953, 10),
+    (543960, 10),
+    (543961, 10),
+    (543962, 10),
+    (543963, 10),
+    (543970, 10),
+    (543971, 10),
+    (543972, 10),
+    (544050, 9),
+    (544051, 9),
+    (544060, 9),
+    (544061, 9),
+    (544070, 9),
+    (544071, 9),
+    (553606, 16),
+    (553607, 16),
+    (553617, 16),
+    (553627, 16),
+    (553705, 20),
+    (553706, 20),
+    (553707, 20),
+    (553716, 20),
+    (553717, 20),
+    (553725, 15),
+    (553726, 15),
+    (553727, 20),
+    (553735, 15),
+    (553736, 15),
+    (553737, 15),
+    (553740, 17),
+    (553741, 17),
+    (553745, 15),
+    (553746, 15),
+    (553747, 15),
+    (553750, 17),
+    (553751, 17),
+    (553752, 17),
+    (553754, 15),
+    (553755, 15),
+    (553756, 15),
+    (553757, 15),
+    (553760, 17),
+    (553761, 17),
+    (553762, 17),
+    (553763, 17),
+    (553764, 15),
+    (553765, 15),
+    (553766, 15),
+    (553767, 15),
+    (553770, 17),
+    (553771, 17),
+    (553772, 17),
+    (553773, 17),
+    (553774, 17),
+    (553775, 15),
+    (553776, 15),
+    (553777, 15),
+    (553800, 20),
+    (553801, 20),
+    (553802, 20),
+    (553803, 20),
+    (553804, 20),
+    (553805, 10),
+    (553806, 10),
+    (553807, 10),
+    (553810, 20),
+    (553811, 20),
+    (553812, 20),
+    (553813, 20),
+    (553814, 20),
+    (553817, 10),
+    (553822, 20),
+    (553823, 20),
+    (553824, 20),
+    (553827, 10),
+    (553833, 20),
+    (553834, 20),
+    (553900, 10),
+    (553901, 10),
+    (553902, 10),
+    (553910, 10),
+    (553911, 10),
+    (553912, 10),
+    (553920, 10),
+    (553921, 10),
+    (553922, 10),
+    (553930, 10),
+    (553931, 10),
+    (553932, 10),
+    (553933, 7),
+    (553940, 15),
+    (553941, 15),
+    (553942, 7),
+    (553943, 7),
+    (553950, 15),
+    (553951, 15),
+    (553952, 15),
+    (553953, 7),
+    (553954, 7),
+    (553955, 7),
+    (553956, 7),
+    (553957, 7),
+    (553960, 15),
+    (553961, 15),
+    (553962, 15),
+    (553963, 7),
+    (553964, 7),
+    (553965, 7),
+    (553966, 7),
+    (553967, 7),
+    (553970, 15),
+    (553971, 15),
+    (553972, 7),
+    (553973, 7),
+    (553974, 7),
+    (553975, 7),
+    (553976, 7),
+    (553977, 7),
+    (554000, 9),
+    (554001, 9),
+    (554002, 8),
+    (554003, 8),
+    (554004, 8),
+    (554005, 8),
+    (554006, 8),
+    (554007, 8),
+    (554010, 9),
+    (554011, 9),
+    (554012, 8),
+    (554013, 8),
+    (554014, 8),
+    (554015, 8),
+    (554016, 8),
+    (554017, 8),
+    (554020, 9),
+    (554021, 9),
+    (554026, 8),
+    (554027, 8),
+    (554030, 9),
+    (554031, 9),
+    (554040, 9),
+    (554041, 9),
+    (554050, 9),
+    (554100, 8),
+    (554101, 8),
+    (554102, 8),
+    (554110, 8),
+    (554111, 8),
+    (563706, 15),
+    (563707, 15),
+    (563716, 15),
+    (563717, 15),
+    (563726, 15),
+    (563727, 15),
+    (563736, 15),
+    (563737, 15),
+    (563746, 15),
+    (563747, 15),
+    (563755, 15),
+    (563756, 15),
+    (563757, 15),
+    (563765, 15),
+    (563766, 15),
+    (563767, 15),
+    (563775, 15),
+    (563776, 15),
+    (563777, 15),
+    (563901, 7),
+    (563902, 7),
+    (563903, 7),
+    (563904, 7),
+    (563905, 7),
+    (563906, 7),
+    (563907, 7),
+    (563912, 7),
+    (563913, 7),
+    (563914, 7),
+    (563915, 7),
+    (563916, 7),
+    (563917, 7),
+    (563923, 7),
+    (563924, 7),
+    (563925, 7),
+    (563926, 7),
+    (563927, 7),
+    (563935, 7),
+    (563936, 7),
+    (563937, 7),
+    (563945, 7),
+    (563946, 7),
+    (563947, 7),
+    (563956, 7),
+    (563957, 7),
+    (563965, 6),
+    (563966, 6),
+    (563967, 6),
+    (563975, 6),
+    (563976, 6),
+    (563977, 6),
+    (564050, 6),
+    (564051, 6),
+    (564060, 6),
+    (564061, 6),
+    (564062, 6),
+    (564065, 4),
+    (564066, 4),
+    (564070, 6),
+    (564071, 6),
+    (564072, 4),
+    (564073, 4),
+    (564074, 4),
+    (564075, 4),
+    (564076, 4),
+    (564077, 4),
+    (564137, 4),
+    (564146, 4),
+    (564147, 4),
+    (564155, 4),
+    (564156, 4),
+    (564157, 4),
+    (564164, 4),
+    (564165, 4),
+    (564166, 4),
+    (564167, 4),
+    (564170, 4),
+    (564171, 4),
+    (564172, 4),
+    (564173, 4),
+    (564174, 4),
+    (564175, 4),
+    (564176, 4),
+    (564177, 4),
+    (573905, 6),
+    (573906, 6),
+    (573907, 6),
+    (573915, 6),
+    (573916, 6),
+    (573917, 6),
+    (573926, 6),
+    (573927, 6),
+    (573937, 6),
+    (573947, 6),
+    (573956, 6),
+    (573957, 6),
+    (573964, 6),
+    (573965, 6),
+    (573966, 6),
+    (573967, 6),
+    (573973, 6),
+    (573974, 6),
+    (573975, 6),
+    (573976, 6),
+    (573977, 6),
+    (574000, 6),
+    (574001, 6),
+    (574002, 6),
+    (574010, 6),
+    (574011, 6),
+    (574012, 6),
+    (574020, 6),
+    (574021, 6),
+    (574022, 6),
+    (574023, 6),
+    (574030, 6),
+    (574031, 6),
+    (574032, 6),
+    (574033, 6),
+    (574040, 6),
+    (574041, 6),
+    (574042, 6),
+    (574043, 6),
+    (574050, 6),
+    (574051, 6),
+    (574052, 6),
+    (574053, 6),
+    (574060, 6),
+    (574061, 6),
+    (574062, 6),
+    (574063, 6),
+    (574064, 6),
+    (574070, 6),
+    (574071, 6),
+    (574072, 6),
+    (574073, 6),
+    (574074, 6),
+    (583900, 15),
+    (583901, 15),
+    (583910, 15),
+    (583911, 15),
+    (583920, 15),
+    (583956, 5),
+    (583957, 5),
+    (583966, 5),
+    (583967, 5),
+    (583976, 5),
+    (583977, 5),
+    (584000, 6),
+    (584001, 6),
+    (584002, 6),
+    (584003, 6),
+    (584004, 6),
+    (584005, 4),
+    (584006, 4),
+    (584007, 4),
+    (584010, 6),
+    (584011, 6),
+    (584012, 6),
+    (584013, 6),
+    (584014, 6),
+    (584015, 4),
+    (584016, 4),
+    (584017, 4),
+    (584020, 6),
+    (584021, 6),
+    (584022, 6),
+    (584023, 6),
+    (584024, 6),
+    (584025, 4),
+    (584026, 4),
+    (584027, 3),
+    (584030, 6),
+    (584031, 6),
+    (584032, 6),
+    (584033, 6),
+    (584034, 6),
+    (584036, 3),
+    (584037, 3),
+    (584040, 6),
+    (584047, 3),
+    (584057, 3),
+    (584066, 3),
+    (584067, 3),
+    (584075, 3),
+    (584076, 3),
+    (584077, 3),
+    (584100, 4),
+    (584101, 4),
+    (584102, 4),
+    (584103, 4),
+    (584104, 4),
+    (584105, 4),
+    (584106, 4),
+    (584107, 4),
+    (584110, 4),
+    (584111, 4),
+    (584114, 4),
+    (584115, 4),
+    (584116, 4),
+    (584117, 4),
+    (584124, 4),
+    (584125, 4),
+    (584126, 4),
+    (584133, 4),
+    (584134, 4),
+    (593900, 6),
+    (593901, 6),
+    (593902, 6),
+    (593903, 6),
+    (593904, 6),
+    (593905, 6),
+    (593910, 6),
+    (593911, 6),
+    (593912, 6),
+    (593913, 6),
+    (593914, 6),
+    (593920, 6),
+    (593921, 6),
+    (593922, 6),
+    (593923, 6),
+    (593924, 6),
+    (593930, 6),
+    (593931, 6),
+    (593932, 6),
+    (594006, 3),
+    (594007, 3),
+    (594016, 3),
+    (594017, 3),
+    (594027, 3),
+    (594037, 3),
+    (594047, 3),
+    (594057, 3),
+    (594067, 3),
+    (594076, 3),
+    (594077, 3),
+    (603940, 2),
+    (603941, 2),
+    (603942, 2),
+    (603950, 2),
+    (603951, 2),
+    (603952, 2),
+    (603953, 2),
+    (603954, 2),
+    (603955, 2),
+    (603960, 2),
+    (603961, 2),
+    (603962, 2),
+    (603963, 2),
+    (603964, 2),
+    (603965, 2),
+    (603966, 2),
+    (603967, 2),
+    (603970, 2),
+    (603971, 2),
+    (603972, 2),
+    (603973, 2),
+    (603974, 2),
+    (603975, 2),
+    (603976, 2),
+    (603977, 2),
+    (604007, 3),
+    (604047, 2),
+    (604053, 2),
+    (604054, 2),
+    (604056, 2),
+    (604057, 2),
+    (604060, 2),
+    (604061, 2),
+    (604062, 2),
+    (604063, 2),
+    (604064, 2),
+    (604065, 2),
+    (604066, 2),
+    (604067, 2),
+    (604070, 2),
+    (604071, 2),
+    (604072, 2),
+    (604073, 2),
+    (604074, 2),
+    (604075, 2),
+    (604076, 2),
+    (604077, 2),
+    (604120, 2),
+    (604130, 2),
+    (604131, 2),
+    (604140, 2),
+    (604141, 2),
+    (604142, 2),
+    (604150, 2),
+    (604151, 2),
+    (604152, 2),
+    (604153, 2),
+    (604154, 2),
+    (604160, 2),
+    (604161, 2),
+    (604162, 2),
+    (604163, 2),
+    (604164, 2),
+    (604165, 2),
+    (604170, 2),
+    (604171, 2),
+    (604172, 2),
+    (604173, 2),
+    (604174, 2),
+    (604175, 2),
+    (604176, 2),
+    (613900, 2),
+    (613901, 2),
+    (613902, 2),
+    (613903, 2),
+    (613904, 2),
+    (613905, 2),
+    (613906, 2),
+    (613907, 2),
+    (613910, 2),
+    (613911, 2),
+    (613912, 2),
+    (613913, 2),
+    (613914, 2),
+    (613915, 2),
+    (613916, 2),
+    (613917, 2),
+    (613921, 2),
+    (613922, 2),
+    (613923, 2),
+    (613924, 2),
+    (613925, 2),
+    (613926, 2),
+    (613927, 2),
+    (613933, 2),
+    (613934, 2),
+    (613935, 2),
+    (613936, 2),
+    (613937, 2),
+    (613944, 2),
+    (613945, 2),
+    (613946, 2),
+    (613947, 2),
+    (614060, 1),
+    (614070, 1),
+    (614071, 1),
+    (624004, 2),
+    (624005, 2),
+    (624006, 2),
+    (624007, 2),
+    (624015, 2),
+    (624016, 2),
+    (624017, 2),
+    (624025, 2),
+    (624026, 2),
+    (624027, 2),
+    (624036, 2),
+    (624037, 2),
+    (624100, 2),
+    (624101, 2),
+    (624102, 2),
+    (624103, 2),
+    (624104, 2),
+    (624105, 2),
+    (624106, 2),
+    (624107, 2),
+    (624110, 2),
+    (624111, 2),
+    (624112, 2),
+    (624113, 2),
+    (624114, 2),
+    (624115, 2),
+    (624116, 2),
+    (624117, 2),
+    (624120, 2),
+    (624121, 2),
+    (624122, 2),
+    (624123, 2),
+    (624124, 2),
+    (624125, 2),
+    (624126, 2),
+    (624127, 2),
+    (624130, 2),
+    (624135, 2),
+    (624136, 2),
+    (624137, 2),
+];
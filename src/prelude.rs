@@ -0,0 +1,5 @@
+//! よく使う型と trait をまとめて読み込むためのモジュール
+//!
+//! `use rust_jp_mesh::prelude::*;` で読み込みます。
+
+pub use crate::{Coordinates, JPMesh, JPMeshError, JPMeshType, MeshCodeIterExt, Rect};
@@ -0,0 +1,90 @@
+//! 整数演算による地域メッシュの範囲 (`rational` feature)
+//!
+//! 地域メッシュの幅はすべて 1/1000 秒の整数倍であるため、範囲の座標を 1/1000 秒単位の整数として誤差なく求めます。
+
+use crate::{
+    Coordinates, JPMesh, JPMeshType, Rect,
+    mesh_type::{ORIGIN_LAT, ORIGIN_LNG},
+};
+
+/// 1度あたりの 1/1000 秒の数
+const MILLISECONDS_PER_DEGREE: i64 = 3_600_000;
+
+impl JPMeshType {
+    /// メッシュ幅を 1/1000 秒単位の整数で取得します。(経度方向, 緯度方向)
+    const fn interval_milliseconds(&self) -> (i64, i64) {
+        (
+            (self.lng_interval_seconds() * 1000.0) as i64,
+            (self.lat_interval_seconds() * 1000.0) as i64,
+        )
+    }
+}
+
+impl JPMesh {
+    /// 地域メッシュの範囲を、1/1000 秒単位の整数で取得します。(最小経度, 最小緯度, 最大経度, 最大緯度)
+    ///
+    /// 格子上の位置とメッシュ幅から整数演算のみで求めるため、浮動小数点の丸めによる誤差を含みません。
+    /// 順序は [`Rect::to_tuple`] と同じです。
+    pub fn bounds_seconds(&self) -> (i64, i64, i64, i64) {
+        let (lng_interval, lat_interval) = self.mesh_type().interval_milliseconds();
+        let (col, row) = self.grid_index();
+        let min_lng = ORIGIN_LNG as i64 * MILLISECONDS_PER_DEGREE + col as i64 * lng_interval;
+        let min_lat = ORIGIN_LAT as i64 * MILLISECONDS_PER_DEGREE + row as i64 * lat_interval;
+        (
+            min_lng,
+            min_lat,
+            min_lng + lng_interval,
+            min_lat + lat_interval,
+        )
+    }
+
+    /// 地域メッシュの範囲を、[`JPMesh::bounds_seconds`] の整数から度に変換して取得します。
+    ///
+    /// 各座標は整数1つを 3,600,000 で割る1回の除算のみで求めるため、IEEE 754 に従うどの環境でも同じ値となります。
+    pub fn to_bounds_exact(&self) -> Rect {
+        let degrees = |milliseconds: i64| milliseconds as f64 / MILLISECONDS_PER_DEGREE as f64;
+        let (min_lng, min_lat, max_lng, max_lat) = self.bounds_seconds();
+        Rect::new(
+            Coordinates::new(degrees(min_lng), degrees(min_lat)),
+            Coordinates::new(degrees(max_lng), degrees(max_lat)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Coordinates, JPMesh, JPMeshType};
+
+    #[test]
+    fn test_bounds_seconds() {
+        // 53394611: 北緯35度40分30秒〜35度41分, 東経139度45分45秒〜139度46分30秒
+        let mesh = JPMesh::from_number(53394611, JPMeshType::Mesh1km);
+        assert_eq!(
+            mesh.bounds_seconds(),
+            (
+                (139 * 3600 + 45 * 60 + 45) * 1000,
+                (35 * 3600 + 40 * 60 + 30) * 1000,
+                (139 * 3600 + 46 * 60 + 30) * 1000,
+                (35 * 3600 + 41 * 60) * 1000,
+            )
+        );
+
+        // 8分の1地域メッシュの幅 (5.625秒, 3.75秒) も整数で表せる
+        let mesh = JPMesh::new(
+            Coordinates::new(139.767125, 35.681236),
+            JPMeshType::Mesh125m,
+        );
+        let (min_lng, min_lat, max_lng, max_lat) = mesh.bounds_seconds();
+        assert_eq!((max_lng - min_lng, max_lat - min_lat), (5625, 3750));
+    }
+
+    #[test]
+    fn test_to_bounds_exact() {
+        for mesh_type in JPMeshType::all() {
+            let mesh = JPMesh::new(Coordinates::new(139.767125, 35.681236), mesh_type);
+            let exact = mesh.to_bounds_exact();
+            assert!(mesh.bounds_approx_eq(&exact, 1e-12), "{:?}", mesh);
+            assert!(exact.includes(Coordinates::new(139.767125, 35.681236)));
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::f64::consts::PI;
+
+use crate::{Coordinates, Rect};
+
+/// Web メルカトル図法で表現できる緯度の上限 (度)。atan(sinh(π)) に等しく、これより高緯度の座標はこの値に切り詰めます。
+const MAX_MERCATOR_LAT: f64 = 85.051_128_779_806_59;
+
+/// 地域メッシュとの変換で扱う Web メルカトルのタイルの最大のズームレベル
+///
+/// 一般的な地図タイルの最大ズームレベルです。ズームレベル22のタイルは赤道で約10m四方であり、
+/// 8分の1地域メッシュ (約125m) よりも十分に小さくなります。これを超えるズームレベルでは、
+/// 1つの地域メッシュと重なるタイルの数が膨大になるため扱いません。
+pub const MAX_WEB_TILE_ZOOM: u8 = 22;
+
+/// ズームレベル `zoom` における、経度に対応するタイルの x 方向の位置を、小数を含めて取得します。
+fn tile_x_fraction(lng: f64, zoom: u8) -> f64 {
+    (lng + 180.0) / 360.0 * tile_count(zoom) as f64
+}
+
+/// ズームレベル `zoom` における、緯度に対応するタイルの y 方向の位置 (北が0) を、小数を含めて取得します。
+fn tile_y_fraction(lat: f64, zoom: u8) -> f64 {
+    let lat = lat.clamp(-MAX_MERCATOR_LAT, MAX_MERCATOR_LAT).to_radians();
+    (1.0 - lat.tan().asinh() / PI) / 2.0 * tile_count(zoom) as f64
+}
+
+/// ズームレベル `zoom` における、1方向あたりのタイルの数を取得します。(`zoom` は [`MAX_WEB_TILE_ZOOM`] 以下)
+fn tile_count(zoom: u8) -> u64 {
+    debug_assert!(zoom <= MAX_WEB_TILE_ZOOM);
+    1 << zoom
+}
+
+/// タイルの範囲を経度・緯度の矩形として取得します。
+///
+/// Web メルカトル図法の逆変換により、タイルの北西端・南東端の座標を求めます。
+/// ズームレベルが [`MAX_WEB_TILE_ZOOM`] を超える場合や、タイルの位置がズームレベルの範囲外の場合は `None` を返します。
+pub(crate) fn tile_bounds(x: u32, y: u32, zoom: u8) -> Option<Rect> {
+    if zoom > MAX_WEB_TILE_ZOOM || u64::from(x.max(y)) >= tile_count(zoom) {
+        return None;
+    }
+    let n = tile_count(zoom) as f64;
+    let lng = |x: f64| x / n * 360.0 - 180.0;
+    let lat = |y: f64| (PI * (1.0 - 2.0 * y / n)).sinh().atan().to_degrees();
+
+    let (x, y) = (x as f64, y as f64);
+    Some(Rect::new(
+        Coordinates::new(lng(x), lat(y + 1.0)),
+        Coordinates::new(lng(x + 1.0), lat(y)),
+    ))
+}
+
+/// 矩形と重なる Web メルカトルのタイルの位置 (x, y) を取得します。
+///
+/// 矩形は南西端を含み北東端を含まないものとして扱います。北の行から順に、各行を西から東へ並べます。
+/// ズームレベルが [`MAX_WEB_TILE_ZOOM`] を超える場合は空の列を返します。
+pub(crate) fn covering_tiles(bounds: &Rect, zoom: u8) -> Vec<(u32, u32)> {
+    if zoom > MAX_WEB_TILE_ZOOM {
+        return Vec::new();
+    }
+    let last = tile_count(zoom) as f64 - 1.0;
+    let (min, max) = (bounds.min(), bounds.max());
+
+    // 北東端にちょうど接するタイルは含めない
+    let axis = |low: f64, high: f64| {
+        let first = low.floor().clamp(0.0, last);
+        let end = (high.ceil() - 1.0).clamp(first, last);
+        first as u32..=end as u32
+    };
+    let xs = axis(
+        tile_x_fraction(min.lng, zoom),
+        tile_x_fraction(max.lng, zoom),
+    );
+    let ys = axis(
+        tile_y_fraction(max.lat, zoom),
+        tile_y_fraction(min.lat, zoom),
+    );
+
+    ys.flat_map(|y| xs.clone().map(move |x| (x, y))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_covering_tiles() {
+        // 東京駅は z=10 でタイル (909, 403) に含まれる
+        let point = Coordinates::new(139.767125, 35.681236);
+        assert_eq!(
+            covering_tiles(&Rect::new(point, point), 10),
+            vec![(909, 403)]
+        );
+
+        // ちょうどタイル1枚分の矩形は、そのタイルのみと重なる
+        let tile = Rect::new(
+            Coordinates::new(0.0, -MAX_MERCATOR_LAT),
+            Coordinates::new(180.0, 0.0),
+        );
+        assert_eq!(covering_tiles(&tile, 1), vec![(1, 1)]);
+
+        // 高緯度は切り詰められる
+        let polar = Rect::new(
+            Coordinates::new(-180.0, 80.0),
+            Coordinates::new(180.0, 90.0),
+        );
+        assert_eq!(covering_tiles(&polar, 0), vec![(0, 0)]);
+        assert_eq!(covering_tiles(&polar, 1), vec![(0, 0), (1, 0)]);
+
+        // 最大のズームレベルを超える場合は扱わない
+        assert_eq!(covering_tiles(&Rect::new(point, point), 22).len(), 1);
+        assert!(covering_tiles(&Rect::new(point, point), 23).is_empty());
+        assert!(covering_tiles(&Rect::new(point, point), 64).is_empty());
+    }
+
+    #[test]
+    fn test_tile_bounds() {
+        let world = tile_bounds(0, 0, 0).unwrap();
+        assert_eq!(world.min().lng, -180.0);
+        assert_eq!(world.max().lng, 180.0);
+        assert!((world.max().lat - MAX_MERCATOR_LAT).abs() < 1e-9);
+        assert!((world.min().lat + MAX_MERCATOR_LAT).abs() < 1e-9);
+
+        // タイルの範囲は、そのタイルのみと重なる
+        for (x, y, zoom) in [(909, 403, 10), (3638, 1612, 12), (0, 0, 3)] {
+            assert_eq!(
+                covering_tiles(&tile_bounds(x, y, zoom).unwrap(), zoom),
+                vec![(x, y)]
+            );
+        }
+
+        // ズームレベルの範囲外の位置や、最大のズームレベルを超える場合は扱わない
+        assert_eq!(tile_bounds(1024, 0, 10), None);
+        assert_eq!(tile_bounds(0, 1024, 10), None);
+        assert_eq!(tile_bounds(0, 0, 23), None);
+        assert_eq!(tile_bounds(0, 0, 255), None);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::Rect;
+
+/// WKB のジオメトリ種別: Polygon
+const WKB_POLYGON: u32 = 3;
+
+/// EWKB で SRID を含むことを示すフラグ
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// 矩形をリトルエンディアンの WKB の Polygon (反時計回りの閉じた外周1つ) に変換します。
+///
+/// `srid` を指定した場合は、SRID を含む EWKB (PostGIS の拡張形式) として出力します。
+pub(crate) fn rect_to_wkb(rect: Rect, srid: Option<u32>) -> Vec<u8> {
+    let ring = rect.as_polygon(false);
+
+    let mut wkb = Vec::with_capacity(1 + 4 + 4 + 4 + 4 + ring.len() * 16);
+    wkb.push(1); // リトルエンディアン
+    match srid {
+        Some(srid) => {
+            wkb.extend_from_slice(&(WKB_POLYGON | EWKB_SRID_FLAG).to_le_bytes());
+            wkb.extend_from_slice(&srid.to_le_bytes());
+        }
+        None => wkb.extend_from_slice(&WKB_POLYGON.to_le_bytes()),
+    }
+    wkb.extend_from_slice(&1u32.to_le_bytes());
+    wkb.extend_from_slice(&(ring.len() as u32).to_le_bytes());
+    for coords in ring {
+        wkb.extend_from_slice(&coords.lng.to_le_bytes());
+        wkb.extend_from_slice(&coords.lat.to_le_bytes());
+    }
+    wkb
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Coordinates;
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn read_f64(bytes: &[u8], offset: usize) -> f64 {
+        f64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_rect_to_wkb() {
+        let rect = Rect::new(Coordinates::new(139.0, 35.0), Coordinates::new(140.0, 36.0));
+
+        let wkb = rect_to_wkb(rect, None);
+        assert_eq!(wkb.len(), 1 + 4 + 4 + 4 + 5 * 16);
+        assert_eq!(wkb[0], 1);
+        assert_eq!(read_u32(&wkb, 1), 3);
+        assert_eq!(read_u32(&wkb, 5), 1);
+        assert_eq!(read_u32(&wkb, 9), 5);
+        // 南西端から始まり、南東端へ進む
+        assert_eq!((read_f64(&wkb, 13), read_f64(&wkb, 21)), (139.0, 35.0));
+        assert_eq!((read_f64(&wkb, 29), read_f64(&wkb, 37)), (140.0, 35.0));
+        assert_eq!(wkb[13..29], wkb[wkb.len() - 16..]);
+
+        let ewkb = rect_to_wkb(rect, Some(4326));
+        assert_eq!(read_u32(&ewkb, 1), 0x2000_0003);
+        assert_eq!(read_u32(&ewkb, 5), 4326);
+        assert_eq!(ewkb[9..], wkb[5..]);
+    }
+}
@@ -0,0 +1,330 @@
+//! 地域メッシュの南西端の座標を、メッシュコードの定義から求めた値と照合します。
+//!
+//! 表の値は `tools/definition_corners.py` で生成したものです。
+//! [総務省統計局「第1章 地域メッシュ統計の特質・沿革」](https://www.stat.go.jp/data/mesh/pdf/gaiyo1.pdf)に
+//! 示された区画の定義 (第1次地域区画は緯度40分・経度1度、第2次地域区画はその8等分、…) に従い、
+//! メッシュコードの各桁から整数演算で求めた南西端の緯度・経度 (1/1000秒単位) です。
+//! 外部の統計データから取得した値ではありませんが、格子の位置から範囲を求める本ライブラリの処理とは
+//! 独立に計算しているため、原点や角の扱いのずれを検出できます。
+
+use rust_jp_mesh::JPMesh;
+use rust_jp_mesh::JPMeshType::{self, *};
+
+/// 1/1000秒単位の値を度に変換する際の許容誤差
+const EPSILON: f64 = 1e-9;
+
+/// (メッシュコード, メッシュの種類, 南西端の緯度, 南西端の経度) (緯度・経度は1/1000秒単位)
+const CORNERS: &[(u64, JPMeshType, i64, i64)] = &[
+    // 東京駅を含む基準地域メッシュ (北緯35度40分30秒・東経139度45分45秒)
+    (53394611, Mesh1km, 128430000, 503145000),
+    // Mesh80km
+    (3848, Mesh80km, 91200000, 532800000),
+    (6540, Mesh80km, 156000000, 504000000),
+    (5833, Mesh80km, 139200000, 478800000),
+    (3636, Mesh80km, 86400000, 489600000),
+    (6339, Mesh80km, 151200000, 500400000),
+    (3332, Mesh80km, 79200000, 475200000),
+    (3322, Mesh80km, 79200000, 439200000),
+    (6852, Mesh80km, 163200000, 547200000),
+    (5248, Mesh80km, 124800000, 532800000),
+    (5347, Mesh80km, 127200000, 529200000),
+    (5929, Mesh80km, 141600000, 464400000),
+    (5748, Mesh80km, 136800000, 532800000),
+    (5225, Mesh80km, 124800000, 450000000),
+    (6236, Mesh80km, 148800000, 489600000),
+    (3152, Mesh80km, 74400000, 547200000),
+    (4442, Mesh80km, 105600000, 511200000),
+    (3930, Mesh80km, 93600000, 468000000),
+    (4631, Mesh80km, 110400000, 471600000),
+    (3547, Mesh80km, 84000000, 529200000),
+    (5852, Mesh80km, 139200000, 547200000),
+    (3440, Mesh80km, 81600000, 504000000),
+    (3829, Mesh80km, 91200000, 464400000),
+    (4041, Mesh80km, 96000000, 507600000),
+    (5129, Mesh80km, 122400000, 464400000),
+    (5944, Mesh80km, 141600000, 518400000),
+    (6825, Mesh80km, 163200000, 450000000),
+    (3442, Mesh80km, 81600000, 511200000),
+    (3544, Mesh80km, 84000000, 518400000),
+    (3426, Mesh80km, 81600000, 453600000),
+    (5426, Mesh80km, 129600000, 453600000),
+    (6229, Mesh80km, 148800000, 464400000),
+    (4141, Mesh80km, 98400000, 507600000),
+    // Mesh10km
+    (454257, Mesh10km, 109500000, 514350000),
+    (343075, Mesh10km, 83700000, 470250000),
+    (435207, Mesh10km, 103200000, 550350000),
+    (612666, Mesh10km, 148200000, 456300000),
+    (324161, Mesh10km, 78600000, 508050000),
+    (604765, Mesh10km, 145800000, 531450000),
+    (422333, Mesh10km, 101700000, 444150000),
+    (534400, Mesh10km, 127200000, 518400000),
+    (562533, Mesh10km, 135300000, 451350000),
+    (672432, Mesh10km, 161700000, 447300000),
+    (574137, Mesh10km, 137700000, 510750000),
+    (353410, Mesh10km, 84300000, 482400000),
+    (463371, Mesh10km, 112500000, 479250000),
+    (682325, Mesh10km, 163800000, 445050000),
+    (533516, Mesh10km, 127500000, 488700000),
+    (653461, Mesh10km, 157800000, 482850000),
+    (402502, Mesh10km, 96000000, 450900000),
+    (413346, Mesh10km, 99600000, 481500000),
+    (534575, Mesh10km, 129300000, 524250000),
+    (643747, Mesh10km, 154800000, 496350000),
+    (633862, Mesh10km, 153000000, 497700000),
+    (563106, Mesh10km, 134400000, 474300000),
+    (644474, Mesh10km, 155700000, 520200000),
+    (592857, Mesh10km, 143100000, 463950000),
+    (572947, Mesh10km, 138000000, 467550000),
+    (362426, Mesh10km, 87000000, 449100000),
+    (362201, Mesh10km, 86400000, 439650000),
+    (313345, Mesh10km, 75600000, 481050000),
+    (625113, Mesh10km, 149100000, 544950000),
+    (364843, Mesh10km, 87600000, 534150000),
+    (524256, Mesh10km, 126300000, 513900000),
+    (552245, Mesh10km, 133200000, 441450000),
+    // Mesh5km
+    (3547601, Mesh5km, 85800000, 529200000),
+    (5525672, Mesh5km, 133800000, 453375000),
+    (6625604, Mesh5km, 160350000, 450225000),
+    (5326662, Mesh5km, 129000000, 456525000),
+    (4752242, Mesh5km, 113400000, 549225000),
+    (4726264, Mesh5km, 113550000, 456525000),
+    (4639233, Mesh5km, 111150000, 501750000),
+    (6331361, Mesh5km, 152100000, 474300000),
+    (3433662, Mesh5km, 83400000, 481725000),
+    (6340753, Mesh5km, 153450000, 506250000),
+    (5443213, Mesh5km, 130350000, 515250000),
+    (4025453, Mesh5km, 97350000, 452250000),
+    (3549721, Mesh5km, 86100000, 537300000),
+    (6333004, Mesh5km, 151350000, 479025000),
+    (3829671, Mesh5km, 93000000, 467550000),
+    (3139423, Mesh5km, 75750000, 501300000),
+    (4733352, Mesh5km, 113700000, 481275000),
+    (6045731, Mesh5km, 146100000, 523350000),
+    (5849062, Mesh5km, 139200000, 539325000),
+    (6544773, Mesh5km, 158250000, 521550000),
+    (4025654, Mesh5km, 97950000, 452475000),
+    (4835362, Mesh5km, 116100000, 488925000),
+    (4338472, Mesh5km, 104400000, 500175000),
+    (3046633, Mesh5km, 73950000, 526950000),
+    (6825023, Mesh5km, 163350000, 450900000),
+    (5438034, Mesh5km, 129750000, 498375000),
+    (6049433, Mesh5km, 145350000, 537750000),
+    (3844713, Mesh5km, 93450000, 518850000),
+    (4525311, Mesh5km, 108900000, 450450000),
+    (4629374, Mesh5km, 111450000, 467775000),
+    (4734512, Mesh5km, 114300000, 483075000),
+    (6325601, Mesh5km, 153000000, 450000000),
+    // Mesh2km
+    (522733085, Mesh2km, 125700000, 458910000),
+    (404161425, Mesh2km, 97920000, 508140000),
+    (453852445, Mesh2km, 109620000, 497880000),
+    (664155405, Mesh2km, 160020000, 509850000),
+    (523301865, Mesh2km, 125040000, 479520000),
+    (434122285, Mesh2km, 103860000, 508860000),
+    (372336465, Mesh2km, 89820000, 445770000),
+    (665012825, Mesh2km, 158940000, 540990000),
+    (612454285, Mesh2km, 147960000, 448560000),
+    (564804605, Mesh2km, 134580000, 534600000),
+    (532651685, Mesh2km, 128880000, 454410000),
+    (642730205, Mesh2km, 154560000, 457200000),
+    (683341285, Mesh2km, 164460000, 479610000),
+    (674207245, Mesh2km, 160860000, 514530000),
+    (414933445, Mesh2km, 99420000, 537930000),
+    (335264825, Mesh2km, 81240000, 549090000),
+    (605045865, Mesh2km, 145440000, 542520000),
+    (583921045, Mesh2km, 139800000, 501030000),
+    (594971445, Mesh2km, 143820000, 537030000),
+    (524235865, Mesh2km, 125940000, 513720000),
+    (474946605, Mesh2km, 114180000, 539100000),
+    (533241225, Mesh2km, 128460000, 475740000),
+    (602657625, Mesh2km, 145680000, 456840000),
+    (683853825, Mesh2km, 164940000, 498240000),
+    (652434005, Mesh2km, 156900000, 448200000),
+    (434567865, Mesh2km, 105240000, 525420000),
+    (492502205, Mesh2km, 117660000, 450900000),
+    (492464225, Mesh2km, 119460000, 448290000),
+    (352530405, Mesh2km, 85020000, 450000000),
+    (663333805, Mesh2km, 159540000, 480150000),
+    (564436285, Mesh2km, 135360000, 521460000),
+    (652441005, Mesh2km, 157200000, 446850000),
+    // Mesh1km
+    (59350228, Mesh1km, 141660000, 487260000),
+    (33402039, Mesh1km, 79890000, 504405000),
+    (66227783, Mesh1km, 160740000, 442485000),
+    (56500166, Mesh1km, 134580000, 540720000),
+    (32497701, Mesh1km, 78900000, 539595000),
+    (59395294, Mesh1km, 143370000, 501480000),
+    (47294071, Mesh1km, 114210000, 464445000),
+    (40412590, Mesh1km, 96870000, 509850000),
+    (45460104, Mesh1km, 108000000, 526230000),
+    (36405484, Mesh1km, 88140000, 505980000),
+    (67527447, Mesh1km, 163020000, 549315000),
+    (50333379, Mesh1km, 121110000, 480555000),
+    (40324488, Mesh1km, 97440000, 477360000),
+    (61506722, Mesh1km, 148260000, 543240000),
+    (45413151, Mesh1km, 109050000, 508095000),
+    (57377605, Mesh1km, 138900000, 496125000),
+    (38344779, Mesh1km, 92610000, 485955000),
+    (40360417, Mesh1km, 96030000, 491715000),
+    (45270637, Mesh1km, 108090000, 460215000),
+    (67322589, Mesh1km, 161640000, 477855000),
+    (57373031, Mesh1km, 137790000, 493245000),
+    (43251484, Mesh1km, 103740000, 451980000),
+    (44240118, Mesh1km, 105630000, 447210000),
+    (40306309, Mesh1km, 97800000, 469755000),
+    (57385785, Mesh1km, 138540000, 500175000),
+    (33401310, Mesh1km, 79530000, 505350000),
+    (68331105, Mesh1km, 163500000, 479475000),
+    (51302168, Mesh1km, 123180000, 468810000),
+    (31377757, Mesh1km, 76650000, 496665000),
+    (39443303, Mesh1km, 94500000, 519885000),
+    (30293213, Mesh1km, 72930000, 465435000),
+    (45380069, Mesh1km, 108180000, 497205000),
+    // Mesh500m
+    (372954171, Mesh500m, 90330000, 466515000),
+    (345271961, Mesh500m, 83970000, 547920000),
+    (664235761, Mesh500m, 159510000, 513720000),
+    (484131492, Mesh500m, 116220000, 508477500),
+    (604613823, Mesh500m, 144555000, 527040000),
+    (594062131, Mesh500m, 143430000, 505035000),
+    (604873951, Mesh500m, 146370000, 534375000),
+    (454901874, Mesh500m, 108255000, 537187500),
+    (584103842, Mesh500m, 139440000, 509152500),
+    (364222094, Mesh500m, 87015000, 512527500),
+    (633362922, Mesh500m, 153270000, 479812500),
+    (632640444, Mesh500m, 152535000, 453802500),
+    (523255034, Mesh500m, 126315000, 477607500),
+    (683934404, Mesh500m, 164235000, 502222500),
+    (533956023, Mesh500m, 128715000, 503190000),
+    (313360213, Mesh500m, 76275000, 478845000),
+    (512555892, Mesh500m, 124140000, 452677500),
+    (303645991, Mesh500m, 73470000, 492255000),
+    (495334362, Mesh500m, 118590000, 552892500),
+    (543363472, Mesh500m, 131520000, 480487500),
+    (615220402, Mesh500m, 147120000, 547222500),
+    (353043003, Mesh500m, 85215000, 469350000),
+    (503455613, Mesh500m, 121695000, 484695000),
+    (472524073, Mesh500m, 113415000, 452115000),
+    (432764143, Mesh500m, 105045000, 459180000),
+    (304070514, Mesh500m, 74265000, 504067500),
+    (643441343, Mesh500m, 154905000, 483030000),
+    (312766394, Mesh500m, 76305000, 460327500),
+    (632465224, Mesh500m, 153075000, 448762500),
+    (364631582, Mesh500m, 87450000, 526432500),
+    (652503002, Mesh500m, 156000000, 451372500),
+    (434350293, Mesh500m, 104775000, 515205000),
+    // Mesh250m
+    (3148444931, Mesh250m, 75735000, 535005000),
+    (3449602442, Mesh250m, 83475000, 536613750),
+    (3147362533, Mesh250m, 75382500, 532125000),
+    (6652517743, Mesh250m, 160132500, 547987500),
+    (6623347812, Mesh250m, 159510000, 444971250),
+    (5846609421, Mesh250m, 141270000, 525802500),
+    (6640456331, Mesh250m, 159795000, 506385000),
+    (5343420912, Mesh250m, 128400000, 516116250),
+    (5331604441, Mesh250m, 129135000, 471802500),
+    (4943168532, Mesh250m, 118155000, 517736250),
+    (4624236421, Mesh250m, 111180000, 447952500),
+    (6735171731, Mesh250m, 161145000, 489465000),
+    (6822367924, Mesh250m, 164317500, 442338750),
+    (4649137812, Mesh250m, 110910000, 538121250),
+    (6535719313, Mesh250m, 158377500, 486585000),
+    (5139732214, Mesh250m, 124567500, 501851250),
+    (4726168341, Mesh250m, 113355000, 456457500),
+    (4938755831, Mesh250m, 119865000, 499410000),
+    (4224623322, Mesh250m, 102690000, 447468750),
+    (6834101933, Mesh250m, 163552500, 482805000),
+    (3043319012, Mesh250m, 73170000, 515261250),
+    (6836055014, Mesh250m, 163357500, 491861250),
+    (6628524422, Mesh250m, 160020000, 461913750),
+    (6741133643, Mesh250m, 161212500, 509242500),
+    (4929447344, Mesh250m, 119032500, 466368750),
+    (3130572721, Mesh250m, 75960000, 471487500),
+    (3123331813, Mesh250m, 75337500, 444510000),
+    (6037171114, Mesh250m, 144337500, 496406250),
+    (3531418514, Mesh250m, 85447500, 472286250),
+    (3632556613, Mesh250m, 88087500, 477720000),
+    (5639314343, Mesh250m, 135442500, 501007500),
+    (5253242423, Mesh250m, 125467500, 552802500),
+    // Mesh125m
+    (39327210241, Mesh125m, 95737500, 476133750),
+    (56445356111, Mesh125m, 136050000, 520020000),
+    (38534251222, Mesh125m, 92550000, 551784375),
+    (57500153121, Mesh125m, 136950000, 540596250),
+    (37323722111, Mesh125m, 89760000, 478440000),
+    (54351440421, Mesh125m, 130035000, 487833750),
+    (57434372114, Mesh125m, 138213750, 516245625),
+    (67433623433, Mesh125m, 161786250, 517657500),
+    (68363410321, Mesh125m, 164145000, 491411250),
+    (30290412122, Mesh125m, 72030000, 466306875),
+    (50533374234, Mesh125m, 121121250, 552358125),
+    (58377664313, Mesh125m, 141498750, 496080000),
+    (45511161343, Mesh125m, 108506250, 544106250),
+    (63525129211, Mesh125m, 152760000, 548077500),
+    (51230152431, Mesh125m, 122572500, 443362500),
+    (67267662323, Mesh125m, 163098750, 456401250),
+    (55285308433, Mesh125m, 133526250, 462532500),
+    (44354469233, Mesh125m, 106991250, 488227500),
+    (38286143413, Mesh125m, 93138750, 461407500),
+    (63377697224, Mesh125m, 153573750, 496254375),
+    (33480375213, Mesh125m, 79413750, 534397500),
+    (51501011331, Mesh125m, 122752500, 540045000),
+    (31491532432, Mesh125m, 74812500, 538768125),
+    (52422107322, Mesh125m, 125415000, 511981875),
+    (43395413121, Mesh125m, 104730000, 502346250),
+    (53405578311, Mesh125m, 128925000, 506610000),
+    (36323068211, Mesh125m, 87480000, 475582500),
+    (65261753421, Mesh125m, 156465000, 456918750),
+    (62302481114, Mesh125m, 149643750, 469850625),
+    (31235647421, Mesh125m, 76035000, 445848750),
+    (53385493223, Mesh125m, 128973750, 498768750),
+    (67325557224, Mesh125m, 162453750, 477804375),
+];
+
+fn milliseconds_to_degrees(value: i64) -> f64 {
+    value as f64 / 3_600_000.0
+}
+
+#[test]
+fn south_west_corner_matches_definition() {
+    for &(number, mesh_type, lat, lng) in CORNERS {
+        let mesh = JPMesh::try_from_number(number, mesh_type)
+            .unwrap_or_else(|err| panic!("{} ({:?}): {}", number, mesh_type, err));
+        assert_eq!(mesh.to_number(), number);
+
+        let min = mesh.to_bounds().min();
+        assert!(
+            (min.lat - milliseconds_to_degrees(lat)).abs() < EPSILON,
+            "{} ({:?}): latitude {} != {}",
+            number,
+            mesh_type,
+            min.lat,
+            milliseconds_to_degrees(lat)
+        );
+        assert!(
+            (min.lng - milliseconds_to_degrees(lng)).abs() < EPSILON,
+            "{} ({:?}): longitude {} != {}",
+            number,
+            mesh_type,
+            min.lng,
+            milliseconds_to_degrees(lng)
+        );
+    }
+}
+
+#[test]
+fn corner_table_covers_all_types() {
+    for mesh_type in JPMeshType::all() {
+        assert!(
+            CORNERS
+                .iter()
+                .filter(|(_, t, _, _)| *t == mesh_type)
+                .count()
+                >= 32,
+            "{:?}",
+            mesh_type
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use proptest::prelude::*;
+use rust_jp_mesh::{Coordinates, JPMesh, JPMeshType};
+
+/// メッシュコードで表現できる範囲内の座標を生成します。
+///
+/// メッシュコードを数値として扱うため、先頭の桁が0となる緯度 6度40分 未満は除きます。
+fn in_domain_coordinates() -> impl Strategy<Value = Coordinates> {
+    (100.0..180.0f64, 6.67..66.66f64).prop_map(|(lng, lat)| Coordinates::new(lng, lat))
+}
+
+fn mesh_types() -> impl Strategy<Value = JPMeshType> {
+    proptest::sample::select(JPMeshType::all().to_vec())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2048))]
+
+    #[test]
+    fn coordinates_are_inside_bounds(coords in in_domain_coordinates(), mesh_type in mesh_types()) {
+        let mesh = JPMesh::new(coords, mesh_type);
+        prop_assert!(mesh.to_bounds().includes(coords), "{:?} is not inside {:?}", coords, mesh);
+    }
+
+    #[test]
+    fn center_re_meshes_to_same_code(coords in in_domain_coordinates(), mesh_type in mesh_types()) {
+        let mesh = JPMesh::new(coords, mesh_type);
+        let center = mesh.to_bounds().center();
+        prop_assert_eq!(JPMesh::new(center, mesh_type), mesh);
+        prop_assert_eq!(JPMesh::from_center(center, mesh_type), mesh);
+    }
+
+    /// メッシュコードの桁の切り捨てによって求めた上位の地域メッシュと、座標から直接求めた地域メッシュの範囲が一致することを確認します。
+    #[test]
+    fn truncated_code_bounds_match_direct_bounds(coords in in_domain_coordinates(), mesh_type in mesh_types()) {
+        let finest = JPMesh::new(coords, JPMeshType::Mesh125m);
+        let direct = JPMesh::new(coords, mesh_type);
+        if let Some(number) = finest.parent_code_number(mesh_type) {
+            let truncated = JPMesh::from_number(number, mesh_type);
+            prop_assert_eq!(truncated, direct);
+            prop_assert_eq!(truncated.to_bounds(), direct.to_bounds());
+        }
+        if mesh_type != JPMeshType::Mesh125m {
+            let parent = finest.parent(mesh_type);
+            prop_assert_eq!(parent.map(|mesh| mesh.to_bounds()), Some(direct.to_bounds()));
+        }
+    }
+
+    #[test]
+    fn number_round_trips(coords in in_domain_coordinates(), mesh_type in mesh_types()) {
+        let mesh = JPMesh::new(coords, mesh_type);
+        prop_assert_eq!(JPMesh::from_number(mesh.to_number(), mesh_type), mesh);
+    }
+}
+
+/// メッシュの南西端の座標を、メッシュの種類とあわせて生成します。
+fn mesh_corners() -> impl Strategy<Value = (Coordinates, JPMeshType)> {
+    mesh_types().prop_flat_map(|mesh_type| {
+        let lng_count = (80.0 / mesh_type.lng_interval()) as u64;
+        let lat_min = (6.67 / mesh_type.lat_interval()).ceil() as u64;
+        let lat_count = (66.66 / mesh_type.lat_interval()) as u64;
+
+        (0..lng_count, lat_min..lat_count).prop_map(move |(col, row)| {
+            let coords = Coordinates::new(
+                100.0 + col as f64 * mesh_type.lng_interval(),
+                row as f64 * mesh_type.lat_interval(),
+            );
+            (coords, mesh_type)
+        })
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2048))]
+
+    #[test]
+    fn all_levels_match_each_level((coords, _) in mesh_corners()) {
+        for (mesh_type, mesh) in JPMesh::all_levels(coords) {
+            prop_assert_eq!(mesh, JPMesh::new(coords, mesh_type));
+            prop_assert!(mesh.to_bounds().includes(coords));
+        }
+    }
+
+    #[test]
+    fn corner_is_inside_bounds((coords, mesh_type) in mesh_corners()) {
+        let mesh = JPMesh::new(coords, mesh_type);
+        prop_assert!(mesh.to_bounds().includes(coords), "{:?} is not inside {:?}", coords, mesh);
+    }
+
+    #[test]
+    fn is_inside_matches_bounds((coords, mesh_type) in mesh_corners()) {
+        let (col, row) = JPMesh::new(coords, mesh_type).grid_index();
+        let candidates = [(col, row), (col.wrapping_sub(1), row), (col, row.wrapping_sub(1))];
+        for mesh in candidates
+            .into_iter()
+            .filter_map(|(col, row)| JPMesh::from_grid_index(col, row, mesh_type))
+        {
+            prop_assert_eq!(mesh.is_inside(coords), mesh.to_bounds().includes(coords));
+        }
+    }
+}
+
+/// メッシュコードから生成した地域メッシュの中心は、どの種類でも同じメッシュコードに戻ることを、
+/// 緯度の異なる第1次地域区画内のすべての地域メッシュ (125m・250m は間引いたもの) で確認します。
+#[test]
+fn center_of_every_cell_re_meshes_to_same_number() {
+    for primary in [3036, 5339, 6841] {
+        let primary = JPMesh::from_number(primary, JPMeshType::Mesh80km);
+        for mesh_type in JPMeshType::all() {
+            let step = match mesh_type {
+                JPMeshType::Mesh125m => 17,
+                JPMeshType::Mesh250m => 5,
+                _ => 1,
+            };
+            for mesh in primary.iter_children(mesh_type).step_by(step) {
+                let number = mesh.to_number();
+                let center = JPMesh::from_number(number, mesh_type).to_bounds().center();
+                assert_eq!(JPMesh::new(center, mesh_type).to_number(), number);
+                assert_eq!(JPMesh::from_center(center, mesh_type).to_number(), number);
+            }
+        }
+    }
+}
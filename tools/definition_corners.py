@@ -0,0 +1,95 @@
+#!/usr/bin/env python3
+"""tests/definition_corners.rs の表 CORNERS を生成します。
+
+使い方: python3 tools/definition_corners.py
+
+各種類の地域メッシュのメッシュコードを乱数 (固定のシード) で32個ずつ選び、区画の定義
+(第1次地域区画は緯度40分・経度1度、第2次地域区画はその8等分、…) に従って、各桁から
+南西端の緯度・経度を 1/1000秒単位の整数で求めます。ライブラリの処理とは独立した整数演算のみで
+計算するため、原点や角の扱いのずれを検出できます。出力を表の部分に貼り付けて使用します。
+"""
+
+import random
+
+# 1/1000秒単位の区画の幅 (緯度, 経度)
+PRIMARY = (40 * 60 * 1000, 3600 * 1000)
+SECONDARY = (5 * 60 * 1000, 450 * 1000)
+MESH5KM = (150 * 1000, 225 * 1000)
+MESH2KM = (60 * 1000, 90 * 1000)
+MESH1KM = (30 * 1000, 45 * 1000)
+SUBDIVISIONS = [(15000, 22500), (7500, 11250), (3750, 5625)]
+
+LEVELS = [
+    ("Mesh80km", 0),
+    ("Mesh10km", 0),
+    ("Mesh5km", 0),
+    ("Mesh2km", 0),
+    ("Mesh1km", 0),
+    ("Mesh500m", 1),
+    ("Mesh250m", 2),
+    ("Mesh125m", 3),
+]
+
+
+def generate(rng, level, subdivisions):
+    p = rng.randint(30, 68)
+    u = rng.randint(22, 53)
+    digits = [p // 10, p % 10, u // 10, u % 10]
+    lat = p * PRIMARY[0]
+    lng = (100 + u) * PRIMARY[1]
+    if level == "Mesh80km":
+        return digits, lat, lng
+
+    q = rng.randint(0, 7)
+    v = rng.randint(0, 7)
+    digits += [q, v]
+    lat += q * SECONDARY[0]
+    lng += v * SECONDARY[1]
+    if level == "Mesh10km":
+        return digits, lat, lng
+
+    if level == "Mesh5km":
+        m = rng.randint(1, 4)
+        digits.append(m)
+        return digits, lat + (m - 1) // 2 * MESH5KM[0], lng + (m - 1) % 2 * MESH5KM[1]
+
+    if level == "Mesh2km":
+        r = rng.randint(0, 4)
+        w = rng.randint(0, 4)
+        digits += [2 * r, 2 * w, 5]
+        return digits, lat + r * MESH2KM[0], lng + w * MESH2KM[1]
+
+    r = rng.randint(0, 9)
+    w = rng.randint(0, 9)
+    digits += [r, w]
+    lat += r * MESH1KM[0]
+    lng += w * MESH1KM[1]
+    for lat_size, lng_size in SUBDIVISIONS[:subdivisions]:
+        m = rng.randint(1, 4)
+        digits.append(m)
+        lat += (m - 1) // 2 * lat_size
+        lng += (m - 1) % 2 * lng_size
+    return digits, lat, lng
+
+
+def main():
+    rng = random.Random(20261016)
+    print("const CORNERS: &[(u64, JPMeshType, i64, i64)] = &[")
+    print("    // 東京駅を含む基準地域メッシュ (北緯35度40分30秒・東経139度45分45秒)")
+    print(f"    (53394611, Mesh1km, {53 * PRIMARY[0] + 4 * SECONDARY[0] + MESH1KM[0]}, "
+          f"{139 * PRIMARY[1] + 6 * SECONDARY[1] + MESH1KM[1]}),")
+    for level, subdivisions in LEVELS:
+        print(f"    // {level}")
+        seen = set()
+        while len(seen) < 32:
+            digits, lat, lng = generate(rng, level, subdivisions)
+            code = int("".join(map(str, digits)))
+            if code in seen:
+                continue
+            seen.add(code)
+            print(f"    ({code}, {level}, {lat}, {lng}),")
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
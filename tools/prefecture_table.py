@@ -0,0 +1,111 @@
+#!/usr/bin/env python3
+"""第2次地域区画 (10km) ごとの都道府県コード表 src/prefecture/table.rs を生成します。
+
+使い方: python3 tools/prefecture_table.py > src/prefecture/table.rs
+
+境界データを使わず、tools/prefecture_points.csv の代表点 (市区町村役場・支所・離島の集落など) から
+推定します。各区画を 4x4 の点で標本化し、それぞれ最も近い代表点の都道府県を数え、最も多いものを
+その区画の都道府県とします。第1次地域区画 (80km) には配下の区画で最も多い都道府県を割り当て、
+それと異なる第2次地域区画だけを例外として出力します。
+"""
+
+import csv
+import math
+import os
+import re
+import sys
+from collections import Counter, defaultdict
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+SAMPLES = 4
+BUCKET = 0.5
+
+
+def read_points():
+    points = []
+    with open(os.path.join(ROOT, "tools", "prefecture_points.csv"), encoding="utf-8") as f:
+        rows = (line for line in f if not line.startswith("#"))
+        for code, _name, lat, lng in csv.reader(rows):
+            points.append((int(code), float(lat), float(lng)))
+    return points
+
+
+def read_primary_meshes():
+    with open(os.path.join(ROOT, "src", "primary_mesh.rs"), encoding="utf-8") as f:
+        source = f.read()
+    body = source.split("const JAPAN_PRIMARY_MESHES", 1)[1].split("];", 1)[0]
+    return [int(code) for code in re.findall(r"\b\d{4}\b", body.split("=", 1)[1])]
+
+
+class NearestIndex:
+    def __init__(self, points):
+        self.buckets = defaultdict(list)
+        for point in points:
+            _, lat, lng = point
+            self.buckets[(math.floor(lat / BUCKET), math.floor(lng / BUCKET))].append(point)
+
+    def nearest(self, lat, lng):
+        scale = math.cos(math.radians(lat))
+        bi, bj = math.floor(lat / BUCKET), math.floor(lng / BUCKET)
+        best = None
+        radius = 0
+        while True:
+            for i in range(bi - radius, bi + radius + 1):
+                for j in range(bj - radius, bj + radius + 1):
+                    if max(abs(i - bi), abs(j - bj)) != radius:
+                        continue
+                    for code, plat, plng in self.buckets.get((i, j), ()):
+                        d = (plat - lat) ** 2 + ((plng - lng) * scale) ** 2
+                        if best is None or d < best[0]:
+                            best = (d, code)
+            # 半径 radius の範囲を調べ終えた時点で、それより外側に近い点が無いことが保証される
+            if best is not None and math.sqrt(best[0]) <= radius * BUCKET * scale:
+                return best[1]
+            radius += 1
+
+
+def secondary_prefecture(index, primary, q, v):
+    p, u = divmod(primary, 100)
+    south = p / 1.5 + q / 12
+    west = 100 + u + v / 8
+    votes = Counter()
+    for i in range(SAMPLES):
+        for j in range(SAMPLES):
+            lat = south + (i + 0.5) / SAMPLES / 12
+            lng = west + (j + 0.5) / SAMPLES / 8
+            votes[index.nearest(lat, lng)] += 1
+    # 同数の場合は都道府県コードの小さい方を採用し、出力を決定的にする
+    return min(votes.items(), key=lambda item: (-item[1], item[0]))[0]
+
+
+def main():
+    index = NearestIndex(read_points())
+    primaries = []
+    exceptions = []
+    for primary in read_primary_meshes():
+        cells = {
+            primary * 100 + q * 10 + v: secondary_prefecture(index, primary, q, v)
+            for q in range(8)
+            for v in range(8)
+        }
+        counts = Counter(cells.values())
+        default = min(counts.items(), key=lambda item: (-item[1], item[0]))[0]
+        primaries.append((primary, default))
+        exceptions.extend((code, pref) for code, pref in cells.items() if pref != default)
+
+    out = sys.stdout
+    out.write("// このファイルは tools/prefecture_table.py で生成されています。直接編集しないでください。\n\n")
+    out.write("/// 第1次地域区画ごとの都道府県コード (メッシュコードの昇順)\n")
+    out.write(f"pub(super) const PRIMARY_PREFECTURES: [(u16, u8); {len(primaries)}] = [\n")
+    for code, pref in primaries:
+        out.write(f"    ({code}, {pref}),\n")
+    out.write("];\n\n")
+    out.write("/// 第1次地域区画の都道府県コードと異なる第2次地域区画の都道府県コード (メッシュコードの昇順)\n")
+    out.write(f"pub(super) const SECONDARY_PREFECTURES: [(u32, u8); {len(exceptions)}] = [\n")
+    for code, pref in exceptions:
+        out.write(f"    ({code}, {pref}),\n")
+    out.write("];\n")
+
+
+if __name__ == "__main__":
+    main()